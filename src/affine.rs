@@ -0,0 +1,199 @@
+//! Units with an offset, such as degrees Celsius
+//!
+//! The units in dimensioned's unit systems are multiplicative: a quantity is its value times its
+//! units, so converting between systems only ever scales the value. Scales of temperature such as
+//! Celsius and Fahrenheit don't fit this, as their zeros are not at absolute zero. A temperature
+//! of 0 °C is 273.15 K, but a temperature *difference* of 1 °C is just 1 K.
+//!
+//! This module keeps the two apart. An `Affine<A>` is a point on the scale of the affine unit `A`,
+//! such as an absolute temperature in degrees Celsius. It stores its value on that scale, and the
+//! offset is only applied when converting it to or from its base unit, such as `si::Kelvin`.
+//! Differences are plain quantities in the base unit, and may be added to or subtracted from an
+//! `Affine`, and subtracting one `Affine` from another gives one, as the offsets cancel. Two
+//! `Affine` values may not be added, as the sum of two temperatures has no meaning.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::affine::{Affine, AffineUnit, Celsius, Fahrenheit};
+//! use dim::si;
+//!
+//! fn main() {
+//!     let freezing = Affine::<Celsius>::new(0.0);
+//!     assert_eq!(freezing.to_base(), 273.15 * si::K);
+//!
+//!     let warmer = freezing + 20.0 * si::K;
+//!     assert_eq!(warmer, Affine::new(20.0));
+//!     assert_eq!(format!("{}", warmer), "20 °C");
+//!
+//!     let body: Affine<Fahrenheit> = Affine::<Celsius>::new(37.0).convert();
+//!     assert!((*body.value() - 98.6).abs() < 1e-9);
+//!
+//!     // A difference of 9 °F is one of 5 K
+//!     assert!((Fahrenheit::difference(9.0) - 5.0 * si::K).value_unsafe.abs() < 1e-12);
+//! }
+//! ```
+//!
+//! Adding two temperatures fails to compile:
+//!
+//! ```rust,compile_fail
+//! extern crate dimensioned as dim;
+//!
+//! use dim::affine::{Affine, Celsius};
+//!
+//! fn main() {
+//!     let sum = Affine::<Celsius>::new(10.0) + Affine::<Celsius>::new(20.0);
+//! }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+use si::{self, SI};
+use Dimensioned;
+
+/// A unit that differs from a multiplicative base unit by both a scale and an offset.
+///
+/// A value `v` in this unit is `(v + OFFSET) * SCALE` in the base unit.
+pub trait AffineUnit {
+    /// The quantity type of the base unit, which differences in this unit are given in.
+    type Base: Dimensioned<Value = f64>;
+
+    /// The size of one of this unit, in the base unit.
+    const SCALE: f64;
+
+    /// The zero of this unit, in this unit, less the zero of the base unit.
+    const OFFSET: f64;
+
+    /// The token to print after the value.
+    const SYMBOL: &'static str;
+
+    /// Give a difference of `delta` in this unit as a quantity in the base unit.
+    ///
+    /// As differences don't depend on where the zero is, only the scale is applied.
+    fn difference(delta: f64) -> Self::Base {
+        Self::Base::new(delta * Self::SCALE)
+    }
+}
+
+/// Degrees Celsius, with a base unit of `si::Kelvin`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Celsius;
+
+impl AffineUnit for Celsius {
+    type Base = si::Kelvin<f64>;
+    const SCALE: f64 = 1.0;
+    const OFFSET: f64 = 273.15;
+    const SYMBOL: &'static str = "°C";
+}
+
+/// Degrees Fahrenheit, with a base unit of `si::Kelvin`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fahrenheit;
+
+impl AffineUnit for Fahrenheit {
+    type Base = si::Kelvin<f64>;
+    const SCALE: f64 = 5.0 / 9.0;
+    const OFFSET: f64 = 459.67;
+    const SYMBOL: &'static str = "°F";
+}
+
+/// A point on the scale of the affine unit `A`, such as an absolute temperature in `Celsius`.
+///
+/// Its value is stored on the scale of `A`. Arithmetic with differences, given as quantities in
+/// the base unit of `A`, only applies the scale of `A`; its offset is applied only by `to_base`,
+/// `from_base`, and `convert`. Subtracting two points also gives a difference in the base unit,
+/// as the offsets cancel. Differences may be added and subtracted when the base unit is in `SI`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::affine::{Affine, Celsius};
+/// use dim::si;
+///
+/// fn main() {
+///     let (hot, cold) = (Affine::<Celsius>::new(30.0), Affine::<Celsius>::new(10.0));
+///     assert_eq!(hot - cold, 20.0 * si::K);
+///     assert_eq!(cold + (hot - cold), hot);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Affine<A> {
+    value: f64,
+    _marker: PhantomData<A>,
+}
+
+impl<A: AffineUnit> Affine<A> {
+    /// Create a point with the value `value` on the scale of `A`.
+    #[inline]
+    pub fn new(value: f64) -> Self {
+        Affine {
+            value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The value of this point on the scale of `A`.
+    #[inline]
+    pub fn value(&self) -> &f64 {
+        &self.value
+    }
+
+    /// Convert to the base unit of `A`, applying both its scale and offset.
+    #[inline]
+    pub fn to_base(self) -> A::Base {
+        A::Base::new((self.value + A::OFFSET) * A::SCALE)
+    }
+
+    /// Convert from the base unit of `A`, applying both its scale and offset.
+    #[inline]
+    pub fn from_base(base: A::Base) -> Self {
+        Affine::new(*base.value_unsafe() / A::SCALE - A::OFFSET)
+    }
+
+    /// Convert to another affine unit with the same base unit, such as from `Celsius` to
+    /// `Fahrenheit`.
+    #[inline]
+    pub fn convert<B: AffineUnit<Base = A::Base>>(self) -> Affine<B> {
+        Affine::from_base(self.to_base())
+    }
+}
+
+impl<A, U> Add<SI<f64, U>> for Affine<A>
+where
+    A: AffineUnit<Base = SI<f64, U>>,
+{
+    type Output = Self;
+    #[inline]
+    fn add(self, delta: SI<f64, U>) -> Self {
+        Affine::new(self.value + *delta.value_unsafe() / A::SCALE)
+    }
+}
+
+impl<A, U> Sub<SI<f64, U>> for Affine<A>
+where
+    A: AffineUnit<Base = SI<f64, U>>,
+{
+    type Output = Self;
+    #[inline]
+    fn sub(self, delta: SI<f64, U>) -> Self {
+        Affine::new(self.value - *delta.value_unsafe() / A::SCALE)
+    }
+}
+
+impl<A: AffineUnit> Sub for Affine<A> {
+    type Output = A::Base;
+    #[inline]
+    fn sub(self, rhs: Self) -> A::Base {
+        A::difference(self.value - rhs.value)
+    }
+}
+
+impl<A: AffineUnit> fmt::Display for Affine<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, " {}", A::SYMBOL)
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+pub fn new() -> System {
+    System {
+        name: "Angle",
+        module: "angle",
+        doc_prelude: r#"A unit system for plane angles
+
+In `SI`, angles are dimensionless, so `si::RAD` and `si::DEG` are both just unitless numbers, and
+nothing stops an angle in degrees from being passed to `sin`. This unit system has radians and
+degrees as units of their own, as base units `Radian` and `Degree`. Converting between them is done
+with `to_radians` and `to_degrees`, and the trigonometric functions are only defined for `Radian`,
+so the conversion can't be forgotten.
+
+As the two are separate base units, a quantity in both, such as the product of a `Radian` and a
+`Degree`, is allowed but has units of `rad*deg`; convert one first.
+
+To bridge to `SI`, a `Radian` may be converted to and from a unitless `SI` quantity with `From`,
+so an angle can be used in formulas such as those in the `physics` module once it is known to be
+in radians. See the `conversion` module for these conversions.
+
+# Example
+```rust
+extern crate dimensioned as dim;
+
+use dim::angle::{self, Degree, Radian};
+use dim::si;
+
+fn main() {
+    let right: Degree<f64> = 90.0 * angle::DEG;
+    let theta: Radian<f64> = right.to_radians();
+    assert!((theta.sin() - 1.0).value_unsafe.abs() < 1e-12);
+    assert_eq!(theta.to_degrees(), right);
+
+    let unitless: si::Unitless<f64> = theta.into();
+    assert_eq!(unitless, 0.5 * std::f64::consts::PI * si::RAD);
+    assert_eq!(Radian::from(si::Unitless::new(2.0)), 2.0 * angle::RAD);
+}
+```
+
+Taking the sine of an angle in degrees fails to compile:
+
+```rust,compile_fail
+extern crate dimensioned as dim;
+
+use dim::angle;
+
+fn main() {
+    let x = (90.0 * angle::DEG).sin();
+}
+```
+
+"#,
+        base: base_units!(
+            RAD: Radian, rad;
+            DEG: Degree, deg;
+        ),
+        derived: derived_units!(),
+        constants: constants!(
+            TURN: Radian = 2.0 * consts::PI * RAD.value_unsafe, "Turn";
+        ),
+        fmt: true,
+        conversions: Vec::new(),
+        from: Vec::new(),
+        refl_blacklist: Vec::new(),
+    }
+}
@@ -25,6 +25,8 @@ Note: this system is incomplete. More derived units and constants are coming.
             S3: Second3 = Second2 * Second;
             S4: Second4 = Second3 * Second;
 
+            PS2: PerSecond2 = Unitless / Second2, AngularAcceleration;
+
             CMPS: CentimeterPerSecond = Centimeter / Second, Velocity;
             CMPS3: CentimeterPerSecond3 = Centimeter / Second3, Jerk;
             CMPS4: CentimeterPerSecond4 = Centimeter / Second4;
@@ -40,6 +42,7 @@ Note: this system is incomplete. More derived units and constants are coming.
             GAL: Gal = Centimeter / Second2, Acceleration;
             DYN: Dyne = Gram *  Gal, Force;
             ERG: Erg = Dyne * Centimeter, Energy;
+            GCM2: GramCentimeter2 = Gram * Centimeter2, MomentOfInertia;
             ERGPS: ErgPerSecond = Erg / Second, Power;
             BA: Barye = Dyne / Centimeter2, Pressure;
             P: Poise = Gram / Centimeter / Second;
@@ -54,6 +57,7 @@ Note: this system is incomplete. More derived units and constants are coming.
             M: Centimeter = HECTO * CM.value_unsafe, "Meter";
         ),
         fmt: false,
+        conversions: Vec::new(),
         from: vec!["SI", "MKS"],
         refl_blacklist: Vec::new(),
     }
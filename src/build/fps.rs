@@ -20,6 +20,7 @@ Note: this system is incomplete. More derived units and constants are coming.
         ),
         constants: constants!(),
         fmt: false,
+        conversions: Vec::new(),
         from: vec![
             // "SI",
             // "MKS",
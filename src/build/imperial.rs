@@ -0,0 +1,50 @@
+use super::*;
+
+pub fn new() -> System {
+    System {
+        name: "Imperial",
+        module: "imperial",
+        doc_prelude: "The imperial and US customary units, with the foot, the pound (mass), and the second as
+base units.
+
+The foot and pound are the international ones, defined as exactly 0.3048 meters and 0.45359237
+kilograms, so quantities convert to and from `SI` exactly, up to floating point error. Other units of
+length and mass, such as the `MI` and `OZ` constants, are multiples of `Foot` and `Pound`.
+
+",
+        base: base_units!(
+            FT: Foot, ft, Length;
+            LB: Pound, lb, Mass;
+            S: Second, s, Time;
+        ),
+        derived: derived_units!(
+            FT2: Foot2 = Foot * Foot, Area;
+            FT3: Foot3 = Foot2 * Foot, Volume;
+            S2: Second2 = Second * Second;
+
+            HZ: Hertz = Unitless / Second, Frequency;
+            FTPS: FootPerSecond = Foot / Second, Velocity;
+            FTPS2: FootPerSecond2 = Foot / Second2, Acceleration;
+            PDL: Poundal, "pdl" = Pound * FootPerSecond2, Force;
+        ),
+        constants: constants!(
+            IN: Foot = FT.value_unsafe / 12.0, "Inch";
+            YD: Foot = 3.0 * FT.value_unsafe, "Yard";
+            MI: Foot = 5_280.0 * FT.value_unsafe, "Mile";
+
+            OZ: Pound = LB.value_unsafe / 16.0, "Ounce";
+            ST: Pound = 14.0 * LB.value_unsafe, "Stone";
+
+            MIN: Second = 60.0 * S.value_unsafe, "Minute";
+            HR: Second = 60.0 * MIN.value_unsafe, "Hour";
+
+            MPH: FootPerSecond = MI.value_unsafe / HR.value_unsafe, "Mile per hour";
+            G0: FootPerSecond2 = 9.80665 / 0.3048 * FTPS2.value_unsafe, "Standard gravity";
+            LBF: Poundal = LB.value_unsafe * G0.value_unsafe, "Pound force";
+        ),
+        fmt: true,
+        conversions: Vec::new(),
+        from: vec!["SI"],
+        refl_blacklist: Vec::new(),
+    }
+}
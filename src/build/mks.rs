@@ -22,6 +22,7 @@ Note: this system is incomplete. More derived units and constants are coming.
         ),
         constants: constants!(),
         fmt: false,
+        conversions: Vec::new(),
         from: vec!["SI", "CGS"],
         refl_blacklist: Vec::new(),
     }
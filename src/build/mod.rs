@@ -22,16 +22,17 @@ pub struct BaseUnit {
 pub struct DerivedUnit {
     pub name: &'static str,
     pub constant: &'static str,
+    pub token: &'static str,
     pub expression: &'static str,
     pub dim: &'static str,
 }
 
 #[derive(Debug)]
 pub struct Constant {
-    pub constant: &'static str,
+    pub constant: String,
     pub unit: &'static str,
-    pub value: &'static str,
-    pub name: &'static str,
+    pub value: String,
+    pub name: String,
 }
 
 impl Constant {
@@ -51,6 +52,9 @@ pub struct System {
     pub from: Vec<&'static str>,
     pub refl_blacklist: Vec<&'static str>,
     pub fmt: bool,
+    /// The base units to write `as_` conversion methods for, each given as the name of its type,
+    /// its symbol without any prefix, and its name.
+    pub conversions: Vec<(&'static str, &'static str, &'static str)>,
 }
 
 impl System {
@@ -72,13 +76,22 @@ Following, we list all of the [base units](#base-units), [derived units](#derive
         }
 
         write!(f, "# Derived Units\n")?;
-        write!(f, "Constant | Unit | Unit Definition | Dimension\n")?;
-        write!(f, "---|---|---|---\n")?;
+        write!(
+            f,
+            "A quantity prints with the token of a derived unit only when its units are exactly those
+of that unit; units are not factored into products of derived and base units. So a torque of
+`N * M` prints with the token of `Joule`, if it has one, and `J / K` prints in base units rather
+than as `J*K^-1`.
+
+"
+        )?;
+        write!(f, "Constant | Unit | Unit Definition | Print Token | Dimension\n")?;
+        write!(f, "---|---|---|---|---\n")?;
         for d in &self.derived {
             write!(
                 f,
-                "{} | {} | {} | {}\n",
-                d.constant, d.name, d.expression, d.dim
+                "{} | {} | {} | {} | {}\n",
+                d.constant, d.name, d.expression, d.token, d.dim
             )?;
         }
 
@@ -128,6 +141,160 @@ Following, we list all of the [base units](#base-units), [derived units](#derive
     }
 }
 
+/// Every SI prefix, with the symbol to name its constants with, and the names of their constants
+/// in `f64prefixes`. The prefixes larger than kilo have no symbol here, as in upper case theirs
+/// would collide with those of the smaller ones, such as mega with milli.
+const SI_PREFIXES: [(&str, &str, &str); 20] = [
+    ("", "YOTTA", "Yotta"),
+    ("", "ZETTA", "Zetta"),
+    ("", "EXA", "Exa"),
+    ("", "PETA", "Peta"),
+    ("", "TERA", "Tera"),
+    ("", "GIGA", "Giga"),
+    ("", "MEGA", "Mega"),
+    ("K", "KILO", "Kilo"),
+    ("H", "HECTO", "Hecto"),
+    ("DA", "DECA", "Deca"),
+    ("D", "DECI", "Deci"),
+    ("C", "CENTI", "Centi"),
+    ("M", "MILLI", "Milli"),
+    ("U", "MICRO", "Micro"),
+    ("N", "NANO", "Nano"),
+    ("P", "PICO", "Pico"),
+    ("F", "FEMTO", "Femto"),
+    ("A", "ATTO", "Atto"),
+    ("Z", "ZEPTO", "Zepto"),
+    ("Y", "YOCTO", "Yocto"),
+];
+
+impl System {
+    /// Add a constant for each unit with every SI prefix, such as `KM` with value
+    /// `KILO * M.value_unsafe`. Each unit is given as its constant and the name of its type.
+    ///
+    /// The constants are named with the symbol of the prefix, or with the whole prefix for those
+    /// without one, as in `MEGAM`. The whole prefix is also used when the symbol would give the
+    /// name of a constant that already exists, as `NS` is for `NewtonSecond`, so it is `NANOS`.
+    fn add_prefixed_constants(&mut self, units: &[(&'static str, &'static str)]) {
+        let mut taken: Vec<String> = self
+            .base
+            .iter()
+            .map(|b| b.constant.to_string())
+            .chain(self.derived.iter().map(|d| d.constant.to_string()))
+            .chain(self.constants.iter().map(|c| c.constant.clone()))
+            .collect();
+
+        for &(constant, unit) in units {
+            for &(symbol, prefix, prefix_name) in &SI_PREFIXES {
+                let short = format!("{}{}", symbol, constant);
+                let name = if !symbol.is_empty() && !taken.contains(&short) {
+                    short
+                } else {
+                    format!("{}{}", prefix, constant)
+                };
+                assert!(
+                    !taken.contains(&name),
+                    "prefixed constant {} already exists in {}",
+                    name,
+                    self.name
+                );
+
+                taken.push(name.clone());
+                self.constants.push(Constant {
+                    constant: name,
+                    unit,
+                    value: format!("{} * {}.value_unsafe", prefix, constant),
+                    name: format!("{}{}", prefix_name, unit.to_lowercase()),
+                });
+            }
+        }
+    }
+}
+
+/// The prefixes for which we generate `as_` conversion methods, with their symbols and the names
+/// of their constants in `f64prefixes`.
+const CONVERSION_PREFIXES: [(&str, &str, &str); 5] = [
+    ("k", "KILO", "kilo"),
+    ("c", "CENTI", "centi"),
+    ("m", "MILLI", "milli"),
+    ("u", "MICRO", "micro"),
+    ("n", "NANO", "nano"),
+];
+
+impl System {
+    /// Write methods, such as `as_km`, giving the value of a base unit quantity expressed in a
+    /// prefixed unit, for each unit in `conversions`. A `Kilogram` is converted as a prefixed gram,
+    /// with `as_g` in place of `as_kg`. The method names are in lower case, so the conversion to
+    /// milliamperes is `as_ma`.
+    fn prefixed_conversions(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for &(unit, symbol, unit_name) in &self.conversions {
+            assert!(
+                self.base.iter().any(|b| b.name == unit),
+                "conversions for {}, which is not a base unit of {}",
+                unit,
+                self.name
+            );
+            let is_kg = unit == "Kilogram";
+
+            // (method, unit, value expression, unit name)
+            let mut conversions = Vec::new();
+            if is_kg {
+                conversions.push((
+                    symbol.to_lowercase(),
+                    symbol.to_string(),
+                    "self.value_unsafe * ::f64prefixes::KILO".to_string(),
+                    format!("{}s", unit_name),
+                ));
+            }
+            for &(prefix, constant, name) in &CONVERSION_PREFIXES {
+                if is_kg && constant == "KILO" {
+                    continue;
+                }
+                let value = if is_kg {
+                    format!(
+                        "self.value_unsafe * ::f64prefixes::KILO / ::f64prefixes::{}",
+                        constant
+                    )
+                } else {
+                    format!("self.value_unsafe / ::f64prefixes::{}", constant)
+                };
+                conversions.push((
+                    format!("{}{}", prefix, symbol).to_lowercase(),
+                    format!("{}{}", prefix, symbol),
+                    value,
+                    format!("{}{}s", name, unit_name),
+                ));
+            }
+
+            let methods: Vec<String> = conversions
+                .iter()
+                .map(|&(ref method, ref unit, ref value, ref name)| {
+                    format!(
+                        "
+        /// Give the value of this quantity in `{1}`, {3}.
+        #[inline]
+        pub fn as_{0}(self) -> f64 {{
+            {2}
+        }}
+",
+                        method, unit, value, name
+                    )
+                })
+                .collect();
+
+            write!(
+                f,
+                "
+    impl {}<f64, inner::{}> {{{}    }}
+",
+                self.name,
+                unit,
+                methods.join("")
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl fmt::Display for System {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         write!(f, "/**\n{}\n\n", self.doc_prelude)?;
@@ -167,14 +334,18 @@ pub mod {} {{
         )?;
 
         for unit in &self.derived {
+            let token = match unit.token {
+                "" => String::new(),
+                t => format!(" as {:?}", t),
+            };
             let dim = match unit.dim {
                 "" => String::new(),
                 d => format!(", {}", d),
             };
             write!(
                 f,
-                "            {}: {} = ({}){};\n",
-                unit.constant, unit.name, unit.expression, dim
+                "            {}: {} = ({}){}{};\n",
+                unit.constant, unit.name, unit.expression, token, dim
             )?;
         }
 
@@ -204,12 +375,20 @@ pub mod {} {{
     #[cfg(feature = \"rand\")]
     impl_rand!({1});
 
+    #[cfg(feature = \"quickcheck\")]
+    impl_quickcheck!({1});
+
+    #[cfg(feature = \"try_from\")]
+    impl_try_from!({1});
+
     pub use self::f64consts::*;
 
 ",
             self.fmt, self.name,
         )?;
 
+        self.prefixed_conversions(f)?;
+
         write!(
             f,
             "
@@ -360,10 +539,11 @@ macro_rules! base_units {
 }
 
 macro_rules! derived_units {
-    ($($constant:ident: $unit:ident =  $e:expr $(, $dim:ident)*;)* ) => (
+    ($($constant:ident: $unit:ident $(, $token:tt)* =  $e:expr $(, $dim:ident)*;)* ) => (
         vec![$(DerivedUnit{
             name: stringify!($unit),
             constant: stringify!($constant),
+            token: concat!($($token),*),
             expression: stringify!($e),
             dim: stringify!($($dim)*),
         }),*];
@@ -374,9 +554,9 @@ macro_rules! constants {
     ($($constant:ident: $unit:ident =  $e:expr, $name: expr;)* ) => (
         vec![$(Constant{
             unit: stringify!($unit),
-            constant: stringify!($constant),
-            value: stringify!($e),
-            name: $name,
+            constant: stringify!($constant).to_string(),
+            value: stringify!($e).to_string(),
+            name: $name.to_string(),
         }),*];
     );
 }
@@ -391,8 +571,10 @@ fn make_system(s: &System) {
     write!(f, "{}", s).unwrap();
 }
 
+mod angle;
 mod cgs;
 mod fps;
+mod imperial;
 mod mks;
 mod si;
 mod ucum;
@@ -400,7 +582,15 @@ mod ucum;
 use std::io::Write;
 
 fn main() {
-    let systems = [si::new(), ucum::new(), mks::new(), cgs::new(), fps::new()];
+    let systems = [
+        si::new(),
+        ucum::new(),
+        mks::new(),
+        cgs::new(),
+        fps::new(),
+        imperial::new(),
+        angle::new(),
+    ];
     for s in &systems {
         make_system(s);
     }
@@ -465,6 +655,20 @@ and the letter `P` instead of `Per`.
 For example, we define `MeterPerSecond2` for acceleration in the SI system, with the accompanying
 constant `MPS2`.
 
+Constants for base units with an SI prefix put the symbol of the prefix, in upper case and with
+`U` for micro, in front of the constant of the unit, so they read as a prefix and a unit rather
+than as a product of units. For example, the SI system has `KM` for kilometers, and `MS` for
+milliseconds rather than meter seconds, with the unit types `Meter` and `Second`. As upper case
+can't tell mega from milli, the prefixes larger than kilo are spelled out, as in `MEGAM` for
+megameters. So is any prefix that would give the name of an existing constant: the SI system has
+`NANOS`, `PICOM`, `PICOA`, `PICOK`, and `HECTOA`, as `NS`, `PM`, `PA`, `PK`, and `HA` are a newton
+second, a per meter, a pascal, a per kelvin, and a hectare. The unit tables of each system list
+every prefixed constant with the name of its unit. Derived units with prefixes can be made with the
+constants in `f64prefixes`, as in `MEGA * PA`.
+
+These prefixed constants are written by the build script of dimensioned, so systems made with
+`make_units!` do not get them; see its documentation for defining them yourself.
+
 ## Completeness
 
 Note that the unit systems included here should not be considered complete. New units and
@@ -529,7 +733,7 @@ mod constant_conversion {{
             .iter()
             .map(|b| b.constant)
             .chain(s.derived.iter().map(|d| d.constant))
-            .chain(s.constants.iter().map(|c| c.constant))
+            .chain(s.constants.iter().map(|c| &c.constant[..]))
             .collect();
         for s2 in systems.iter().filter(|s2| s2.name != s.name) {
             if s.from.iter().any(|&f| f == s2.name) && s2.from.iter().any(|&f| f == s.name) {
@@ -555,7 +759,7 @@ mod constant_conversion {{
                 .iter()
                 .map(|b| b.constant)
                 .chain(s2.derived.iter().map(|d| d.constant))
-                .chain(s2.constants.iter().map(|c| c.constant))
+                .chain(s2.constants.iter().map(|c| &c.constant[..]))
                 .collect();
             for c in constants1.intersection(&constants2) {
                 write!(
@@ -1,7 +1,7 @@
 use super::*;
 
 pub fn new() -> System {
-    System {
+    let mut system = System {
         name: "SI",
         module: "si",
         doc_prelude: "The International System of Units (SI)
@@ -21,18 +21,18 @@ Where experimental values are used for constants, the values are obtained from t
         ),
         derived: derived_units!(
             HZ: Hertz = Unitless / Second, Frequency;
-            N: Newton = Kilogram * Meter / Second2, Force;
-            PA: Pascal = Newton / Meter2, Pressure;
-            J: Joule = Newton * Meter, Energy;
-            W: Watt = Joule / Second, Power;
-            C: Coulomb = Second * Ampere, Charge;
-            V: Volt = Watt / Ampere, ElectricPotential;
-            F: Farad = Coulomb / Volt, Capacitance;
-            OHM: Ohm = Volt / Ampere, Resistance;
-            SIE: Siemens = Ampere / Volt, Conductance;
-            WB: Weber = Joule / Ampere, MagneticFlux;
-            T: Tesla = Weber / Meter2;
-            H: Henry = Ohm * Second, Inductance;
+            N: Newton, "N" = Kilogram * Meter / Second2, Force;
+            PA: Pascal, "Pa" = Newton / Meter2, Pressure;
+            J: Joule, "J" = Newton * Meter, Energy;
+            W: Watt, "W" = Joule / Second, Power;
+            C: Coulomb, "C" = Second * Ampere, Charge;
+            V: Volt, "V" = Watt / Ampere, ElectricPotential;
+            F: Farad, "F" = Coulomb / Volt, Capacitance;
+            OHM: Ohm, "Ω" = Volt / Ampere, Resistance;
+            SIE: Siemens, "S" = Ampere / Volt, Conductance;
+            WB: Weber, "Wb" = Joule / Ampere, MagneticFlux;
+            T: Tesla, "T" = Weber / Meter2;
+            H: Henry, "H" = Ohm * Second, Inductance;
             LM: Lumen = Candela;
             LX: Lux = Candela / Meter2;
             BQ: Becquerel = Hertz;
@@ -51,7 +51,7 @@ Where experimental values are used for constants, the values are obtained from t
             S3: Second3 = Second2 * Second;
             S4: Second4 = Second3 * Second;
 
-            PS2: PerSecond2 = Unitless / Second2;
+            PS2: PerSecond2 = Unitless / Second2, AngularAcceleration;
             PS3: PerSecond3 = Unitless / Second3;
             PS4: PerSecond4 = Unitless / Second4;
 
@@ -72,6 +72,7 @@ Where experimental values are used for constants, the values are obtained from t
             JS: JouleSecond = Joule * Second;
             NPS: NewtonPerSecond = Newton / Second;
 
+            KGM2: KilogramMeter2 = Kilogram * Meter2, MomentOfInertia;
             KGPM: KilogramPerMeter = Kilogram / Meter;
             KGPM2: KilogramPerMeter2 = Kilogram / Meter2;
             KGPM3: KilogramPerMeter3 = Kilogram / Meter3;
@@ -186,6 +187,15 @@ Where experimental values are used for constants, the values are obtained from t
             LBF: Newton = 4.4482216152605 * N.value_unsafe, "Pound force";
         ),
         fmt: true,
+        conversions: vec![
+            ("Meter", "m", "meter"),
+            ("Kilogram", "g", "gram"),
+            ("Second", "s", "second"),
+            ("Ampere", "A", "ampere"),
+            ("Kelvin", "K", "kelvin"),
+            ("Candela", "cd", "candela"),
+            ("Mole", "mol", "mole"),
+        ],
         from: vec!["UCUM"],
         refl_blacklist: vec![
             "MOL",
@@ -203,5 +213,12 @@ Where experimental values are used for constants, the values are obtained from t
             "MOLPM3",
             "M3PMOLS",
         ],
-    }
+    };
+    system.add_prefixed_constants(&[
+        ("M", "Meter"),
+        ("S", "Second"),
+        ("A", "Ampere"),
+        ("K", "Kelvin"),
+    ]);
+    system
 }
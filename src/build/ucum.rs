@@ -370,6 +370,7 @@ in function signatures), this is something to bear in mind.
             SMOOT: Meter = 67.0 * IN_I.value_unsafe, "Smoot";
         ),
         fmt: true,
+        conversions: Vec::new(),
         from: vec!["SI"],
         refl_blacklist: vec!["RAD", "SR", "GON", "DEG", "CIRC", "LM", "SPH", "PHT", "LX"],
     }
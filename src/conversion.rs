@@ -24,6 +24,66 @@
 //!
 //! * `CGS` to `MKS`
 //! * `MKS` to `CGS`
+//!
+//! * `SI` to `Imperial`: This is defined only for `SI` units that are a combination of `Meter`,
+//! `Kilogram`, and `Second`.
+//!
+//! * `Imperial` to `SI`
+//!
+//! * `Angle` to `SI` and `SI` to `Angle`: These are defined only between a `Radian` and a
+//! unitless `SI` quantity, as `SI` measures angles in radians. A `Degree` must first be converted
+//! with `to_radians`, which, along with `to_degrees`, is also defined here.
+//!
+//! With the `std` feature, we also convert between `std::time::Duration` and `si::Second<f64>`.
+//! As units in dimensioned carry no scale, a quantity of `Second`s always counts seconds; the
+//! integer nanoseconds of a `Duration` become a fractional number of seconds. A `Duration`
+//! survives the round trip at nanosecond resolution as long as it is shorter than about 100 days,
+//! beyond which `f64` no longer has the precision for every nanosecond.
+//!
+//! An integer time, `si::Second<i64>`, is also a number of seconds, and converts to a `Duration`
+//! with `to_duration` and `TryFrom`; converting back with `from_duration` drops any fraction of a
+//! second. For times that must keep every nanosecond, there is instead the `Nanoseconds` type,
+//! which is not a quantity but a plain count of nanoseconds. It converts exactly to and from a
+//! `Duration`, using only integer arithmetic, with `from_duration` and `to_duration`, or with
+//! `TryFrom` under the `try_from` feature, and to and from `SI` times with `to_seconds` and
+//! `from_seconds`. The conversions with a `Duration` fail for negative times, and for durations
+//! longer than `i64::max_value()` nanoseconds, or about 292 years.
+//!
+//! A `Duration` can also be multiplied by an `SI` quantity with a value type of `f64`, from either
+//! side, which multiplies the quantity by the duration in seconds. For example, a velocity times a
+//! `Duration` is a length. Multiplying with the quantity on the left is not available with the
+//! `oibit` feature, where it would overlap with multiplying the value by any other type.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::{cgs, si};
+//!
+//! fn main() {
+//!     assert_eq!(cgs::CGS::from(1.0 * si::M), 100.0 * cgs::CM);
+//!
+//!     let force: cgs::Dyne<f64> = (1.0 * si::N).into();
+//!     assert_eq!(force, 1e5 * cgs::DYN);
+//! }
+//! ```
+//!
+//! The units after conversion are computed from the units before, so a quantity can only be
+//! converted into one with matching units:
+//!
+//! ```rust,compile_fail
+//! extern crate dimensioned as dim;
+//!
+//! use dim::{cgs, si};
+//!
+//! fn main() {
+//!     let force: cgs::Erg<f64> = (1.0 * si::N).into();
+//! }
+//! ```
+
+use core::fmt;
+use core::ops::{Add, Sub};
+use si;
 
 mod to_si {
     // From UCUM
@@ -57,6 +117,55 @@ mod to_si {
             SI::new(other.value_unsafe * fac)
         }
     }
+
+    // From Imperial
+    use imperial;
+    impl<V, Foot, Pound, Second> From<imperial::Imperial<V, tarr![Foot, Pound, Second]>>
+        for SI<Prod<V, f64>, tarr![Foot, Pound, Second, Z0, Z0, Z0, Z0]>
+    where
+        Foot: Integer,
+        Pound: Integer,
+        Second: Integer,
+        V: Mul<f64>,
+    {
+        fn from(other: imperial::Imperial<V, tarr![Foot, Pound, Second]>) -> Self {
+            let ftfac = FloatCore::powi(0.3048, Foot::to_i32());
+            let lbfac = FloatCore::powi(0.453_592_37, Pound::to_i32());
+
+            let fac = ftfac * lbfac;
+
+            SI::new(other.value_unsafe * fac)
+        }
+    }
+}
+
+mod to_imperial {
+    // From SI
+    use core::convert::From;
+    use core::ops::Mul;
+    use imperial::Imperial;
+    use num_traits::float::FloatCore;
+    use si;
+    use typenum::{Integer, Prod, Z0};
+
+    impl<V, Meter, Kilogram, Second>
+        From<si::SI<V, tarr![Meter, Kilogram, Second, Z0, Z0, Z0, Z0]>>
+        for Imperial<Prod<V, f64>, tarr![Meter, Kilogram, Second]>
+    where
+        Meter: Integer,
+        Kilogram: Integer,
+        Second: Integer,
+        V: Mul<f64>,
+    {
+        fn from(other: si::SI<V, tarr![Meter, Kilogram, Second, Z0, Z0, Z0, Z0]>) -> Self {
+            let mfac = FloatCore::powi(0.3048, -Meter::to_i32());
+            let kgfac = FloatCore::powi(0.453_592_37, -Kilogram::to_i32());
+
+            let fac = mfac * kgfac;
+
+            Imperial::new(other.value_unsafe * fac)
+        }
+    }
 }
 
 mod to_ucum {
@@ -225,3 +334,333 @@ mod to_mks {
         }
     }
 }
+
+mod angle {
+    use core::convert::From;
+    use si::{self, SI};
+    use unit_systems::angle::{Degree, Radian, Unitless};
+
+    macro_rules! impl_angle {
+        ($t:ident) => {
+            impl Degree<$t> {
+                /// Convert an angle in degrees to radians.
+                #[inline]
+                pub fn to_radians(self) -> Radian<$t> {
+                    Radian::new(self.value_unsafe * (::core::$t::consts::PI / 180.0))
+                }
+            }
+
+            impl Radian<$t> {
+                /// Convert an angle in radians to degrees.
+                #[inline]
+                pub fn to_degrees(self) -> Degree<$t> {
+                    Degree::new(self.value_unsafe * (180.0 / ::core::$t::consts::PI))
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl Radian<$t> {
+                /// Compute the sine of an angle.
+                #[inline]
+                pub fn sin(self) -> Unitless<$t> {
+                    Unitless::new(self.value_unsafe.sin())
+                }
+                /// Compute the cosine of an angle.
+                #[inline]
+                pub fn cos(self) -> Unitless<$t> {
+                    Unitless::new(self.value_unsafe.cos())
+                }
+                /// Compute the tangent of an angle.
+                #[inline]
+                pub fn tan(self) -> Unitless<$t> {
+                    Unitless::new(self.value_unsafe.tan())
+                }
+            }
+        };
+    }
+    impl_angle!(f32);
+    impl_angle!(f64);
+
+    impl<V> From<Radian<V>> for SI<V, si::inner::Unitless> {
+        #[inline]
+        fn from(theta: Radian<V>) -> Self {
+            SI::new(theta.value_unsafe)
+        }
+    }
+
+    impl<V> From<SI<V, si::inner::Unitless>> for Radian<V> {
+        #[inline]
+        fn from(theta: SI<V, si::inner::Unitless>) -> Self {
+            Radian::new(theta.value_unsafe)
+        }
+    }
+}
+
+/// A count of nanoseconds.
+///
+/// As a unit in dimensioned carries no scale, an `si::Second<i64>` always counts whole seconds.
+/// This type is for when a time must instead keep every nanosecond; it is not a quantity, so it
+/// must be converted with `to_seconds` before it can be used with other units.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::conversion::Nanoseconds;
+/// use dim::si;
+///
+/// fn main() {
+///     let t = Nanoseconds(1_500_000_000);
+///     assert_eq!(t.to_seconds(), 1.5 * si::S);
+///     let two = Nanoseconds::from_seconds(2 * si::i64consts::S);
+///     assert_eq!(two, Some(Nanoseconds(2_000_000_000)));
+///     assert_eq!(t + Nanoseconds(1), Nanoseconds(1_500_000_001));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nanoseconds(pub i64);
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+impl Nanoseconds {
+    /// Convert a time in seconds to nanoseconds.
+    ///
+    /// This gives `None` if the time is too long for an `i64` of nanoseconds.
+    #[inline]
+    pub fn from_seconds(t: si::Second<i64>) -> Option<Self> {
+        t.value_unsafe.checked_mul(NANOS_PER_SEC).map(Nanoseconds)
+    }
+
+    /// Convert this time to seconds.
+    ///
+    /// Counts of more than 2<sup>53</sup> nanoseconds, or about 104 days, may be rounded.
+    #[inline]
+    pub fn to_seconds(self) -> si::Second<f64> {
+        si::Second::new(self.0 as f64 * 1e-9)
+    }
+}
+
+impl Add for Nanoseconds {
+    type Output = Nanoseconds;
+    #[inline]
+    fn add(self, rhs: Nanoseconds) -> Nanoseconds {
+        Nanoseconds(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Nanoseconds {
+    type Output = Nanoseconds;
+    #[inline]
+    fn sub(self, rhs: Nanoseconds) -> Nanoseconds {
+        Nanoseconds(self.0 - rhs.0)
+    }
+}
+
+impl fmt::Display for Nanoseconds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ns", self.0)
+    }
+}
+
+/// The error for a time that doesn't fit in a `std::time::Duration`, or the reverse.
+#[cfg(all(feature = "std", feature = "try_from"))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DurationRangeError;
+
+#[cfg(all(feature = "std", feature = "try_from"))]
+impl ::std::fmt::Display for DurationRangeError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "time out of range for conversion")
+    }
+}
+
+#[cfg(all(feature = "std", feature = "try_from"))]
+impl ::std::error::Error for DurationRangeError {
+    fn description(&self) -> &str {
+        "time out of range for conversion"
+    }
+}
+
+#[cfg(feature = "std")]
+mod duration {
+    #[cfg(feature = "try_from")]
+    use super::DurationRangeError;
+    use super::{Nanoseconds, NANOS_PER_SEC};
+    use core::convert::From;
+    #[cfg(feature = "try_from")]
+    use core::convert::TryFrom;
+    use core::ops::{Add, Mul};
+    use si::{self, SI};
+    use std::time::Duration;
+    use typenum::Sum;
+
+    impl From<Duration> for si::Second<f64> {
+        fn from(other: Duration) -> Self {
+            si::Second::new(other.as_secs() as f64 + f64::from(other.subsec_nanos()) * 1e-9)
+        }
+    }
+
+    impl si::Second<f64> {
+        /// Convert this time to a `std::time::Duration`, rounding to the nearest nanosecond.
+        ///
+        /// As a `Duration` cannot be negative, this gives `None` for negative times, as well as
+        /// for times that are not finite or too large to fit.
+        ///
+        /// # Example
+        /// ```rust
+        /// extern crate dimensioned as dim;
+        /// use dim::si;
+        /// use std::time::Duration;
+        ///
+        /// fn main() {
+        ///     let t = 1.5 * si::S;
+        ///     assert_eq!(t.to_duration(), Some(Duration::new(1, 500_000_000)));
+        ///     assert_eq!(si::Second::from(Duration::new(1, 500_000_000)), t);
+        ///
+        ///     assert_eq!((-1.5 * si::S).to_duration(), None);
+        /// }
+        /// ```
+        pub fn to_duration(self) -> Option<Duration> {
+            let secs = self.value_unsafe;
+            if !(secs >= 0.0 && secs < u64::max_value() as f64) {
+                return None;
+            }
+            let whole = secs.trunc();
+            // Duration::new carries any nanoseconds that round up to a whole second
+            let nanos = ((secs - whole) * 1e9).round() as u32;
+            Some(Duration::new(whole as u64, nanos))
+        }
+    }
+
+    impl si::Second<i64> {
+        /// Convert a `std::time::Duration` to whole seconds, dropping any fraction of a second as
+        /// `Duration::as_secs` does.
+        ///
+        /// This gives `None` if the duration is too long for an `i64` of seconds. Use
+        /// `Nanoseconds::from_duration` to keep the fraction.
+        ///
+        /// # Example
+        /// ```rust
+        /// extern crate dimensioned as dim;
+        /// use dim::si;
+        /// use std::time::Duration;
+        ///
+        /// fn main() {
+        ///     let t = si::Second::from_duration(Duration::new(5, 999_999_999));
+        ///     assert_eq!(t, Some(5 * si::i64consts::S));
+        ///     assert_eq!((5 * si::i64consts::S).to_duration(), Some(Duration::new(5, 0)));
+        ///
+        ///     assert_eq!((-5 * si::i64consts::S).to_duration(), None);
+        /// }
+        /// ```
+        pub fn from_duration(other: Duration) -> Option<Self> {
+            if other.as_secs() > i64::max_value() as u64 {
+                return None;
+            }
+            Some(si::Second::new(other.as_secs() as i64))
+        }
+
+        /// Convert this time to a `std::time::Duration`.
+        ///
+        /// As a `Duration` cannot be negative, this gives `None` for negative times.
+        pub fn to_duration(self) -> Option<Duration> {
+            if self.value_unsafe < 0 {
+                return None;
+            }
+            Some(Duration::from_secs(self.value_unsafe as u64))
+        }
+    }
+
+    impl Nanoseconds {
+        /// Convert a `std::time::Duration` to nanoseconds.
+        ///
+        /// This gives `None` if the duration is too long for an `i64` of nanoseconds.
+        ///
+        /// # Example
+        /// ```rust
+        /// extern crate dimensioned as dim;
+        /// use dim::conversion::Nanoseconds;
+        /// use std::time::Duration;
+        ///
+        /// fn main() {
+        ///     let t = Nanoseconds::from_duration(Duration::new(1, 500_000_001));
+        ///     assert_eq!(t, Some(Nanoseconds(1_500_000_001)));
+        ///     let d = Nanoseconds(1_500_000_001).to_duration();
+        ///     assert_eq!(d, Some(Duration::new(1, 500_000_001)));
+        ///
+        ///     assert_eq!(Nanoseconds(-1).to_duration(), None);
+        /// }
+        /// ```
+        pub fn from_duration(other: Duration) -> Option<Self> {
+            if other.as_secs() > i64::max_value() as u64 {
+                return None;
+            }
+            (other.as_secs() as i64)
+                .checked_mul(NANOS_PER_SEC)
+                .and_then(|nanos| nanos.checked_add(i64::from(other.subsec_nanos())))
+                .map(Nanoseconds)
+        }
+
+        /// Convert this time to a `std::time::Duration`.
+        ///
+        /// As a `Duration` cannot be negative, this gives `None` for negative times.
+        pub fn to_duration(self) -> Option<Duration> {
+            if self.0 < 0 {
+                return None;
+            }
+            Some(Duration::new(
+                (self.0 / NANOS_PER_SEC) as u64,
+                (self.0 % NANOS_PER_SEC) as u32,
+            ))
+        }
+    }
+
+    #[cfg(feature = "try_from")]
+    impl TryFrom<si::Second<i64>> for Duration {
+        type Error = DurationRangeError;
+        #[inline]
+        fn try_from(other: si::Second<i64>) -> Result<Self, DurationRangeError> {
+            other.to_duration().ok_or(DurationRangeError)
+        }
+    }
+
+    #[cfg(feature = "try_from")]
+    impl TryFrom<Duration> for Nanoseconds {
+        type Error = DurationRangeError;
+        #[inline]
+        fn try_from(other: Duration) -> Result<Self, DurationRangeError> {
+            Nanoseconds::from_duration(other).ok_or(DurationRangeError)
+        }
+    }
+
+    #[cfg(feature = "try_from")]
+    impl TryFrom<Nanoseconds> for Duration {
+        type Error = DurationRangeError;
+        #[inline]
+        fn try_from(other: Nanoseconds) -> Result<Self, DurationRangeError> {
+            other.to_duration().ok_or(DurationRangeError)
+        }
+    }
+
+    #[cfg(not(feature = "oibit"))]
+    impl<U> Mul<Duration> for SI<f64, U>
+    where
+        U: Add<si::inner::Second>,
+    {
+        type Output = SI<f64, Sum<U, si::inner::Second>>;
+        #[inline]
+        fn mul(self, rhs: Duration) -> Self::Output {
+            self * si::Second::from(rhs)
+        }
+    }
+
+    impl<U> Mul<SI<f64, U>> for Duration
+    where
+        si::inner::Second: Add<U>,
+    {
+        type Output = SI<f64, Sum<si::inner::Second, U>>;
+        #[inline]
+        fn mul(self, rhs: SI<f64, U>) -> Self::Output {
+            si::Second::from(self) * rhs
+        }
+    }
+}
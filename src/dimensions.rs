@@ -52,6 +52,9 @@ pub trait Velocity: Dimensioned {}
 pub trait Acceleration: Dimensioned {}
 pub trait Jerk: Dimensioned {}
 
+pub trait AngularAcceleration: Dimensioned {}
+pub trait MomentOfInertia: Dimensioned {}
+
 pub trait Charge: Dimensioned {}
 pub trait Current: Dimensioned {}
 pub trait ElectricPotential: Dimensioned {}
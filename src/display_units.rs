@@ -0,0 +1,115 @@
+//! Units for display that are chosen at runtime
+//!
+//! The units of a quantity are fixed at compile time, but a user interface may let its user pick
+//! which unit a value is shown in. The enums here list display units for a dimension, along with
+//! the factor that converts each to the base unit of `SI`, so that a typed quantity can be
+//! converted to whichever unit is chosen.
+//!
+//! Currently, only length is supported.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::display_units::LengthUnit;
+//! use dim::si;
+//!
+//! fn main() {
+//!     let x = 2500.0 * si::M;
+//!     let unit = LengthUnit::Kilometer;
+//!
+//!     assert_eq!(format!("{} {}", unit.value_of(x), unit), "2.5 km");
+//! }
+//! ```
+
+use core::fmt;
+use si;
+
+/// A unit of length, for displaying lengths in a unit chosen at runtime.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// The meter, the base unit of length in `SI`
+    Meter,
+    /// The kilometer, `1000 m`
+    Kilometer,
+    /// The centimeter, `0.01 m`
+    Centimeter,
+    /// The millimeter, `0.001 m`
+    Millimeter,
+    /// The micrometer, `1e-6 m`
+    Micrometer,
+    /// The nanometer, `1e-9 m`
+    Nanometer,
+    /// The international inch, `0.0254 m`
+    Inch,
+    /// The international foot, `0.3048 m`
+    Foot,
+    /// The international yard, `0.9144 m`
+    Yard,
+    /// The international mile, `1609.344 m`
+    Mile,
+}
+
+impl LengthUnit {
+    /// Every unit of length, in the order they are declared; useful for populating a menu.
+    pub const ALL: [LengthUnit; 10] = [
+        LengthUnit::Meter,
+        LengthUnit::Kilometer,
+        LengthUnit::Centimeter,
+        LengthUnit::Millimeter,
+        LengthUnit::Micrometer,
+        LengthUnit::Nanometer,
+        LengthUnit::Inch,
+        LengthUnit::Foot,
+        LengthUnit::Yard,
+        LengthUnit::Mile,
+    ];
+
+    /// The number of meters in one of this unit.
+    pub fn to_base_factor(self) -> f64 {
+        match self {
+            LengthUnit::Meter => 1.0,
+            LengthUnit::Kilometer => 1e3,
+            LengthUnit::Centimeter => 1e-2,
+            LengthUnit::Millimeter => 1e-3,
+            LengthUnit::Micrometer => 1e-6,
+            LengthUnit::Nanometer => 1e-9,
+            LengthUnit::Inch => 0.0254,
+            LengthUnit::Foot => 0.3048,
+            LengthUnit::Yard => 0.9144,
+            LengthUnit::Mile => 1609.344,
+        }
+    }
+
+    /// The symbol for this unit, as it is printed.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            LengthUnit::Meter => "m",
+            LengthUnit::Kilometer => "km",
+            LengthUnit::Centimeter => "cm",
+            LengthUnit::Millimeter => "mm",
+            LengthUnit::Micrometer => "µm",
+            LengthUnit::Nanometer => "nm",
+            LengthUnit::Inch => "in",
+            LengthUnit::Foot => "ft",
+            LengthUnit::Yard => "yd",
+            LengthUnit::Mile => "mi",
+        }
+    }
+
+    /// Give the value of `length` when expressed in this unit.
+    pub fn value_of(self, length: si::Meter<f64>) -> f64 {
+        length.value_unsafe / self.to_base_factor()
+    }
+
+    /// Create a length from a value expressed in this unit.
+    pub fn length(self, value: f64) -> si::Meter<f64> {
+        si::Meter::new(value * self.to_base_factor())
+    }
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.symbol())
+    }
+}
@@ -0,0 +1,125 @@
+//! Reading quantities from environment variables
+//!
+//! Configuration is often given in environment variables, as bare numbers. The function `from_env`
+//! reads one and gives it the units of the quantity type asked for, so that the units of a setting
+//! are fixed by the type system where it is used, rather than by documentation alone.
+//!
+//! Only the number is read by `from_env`; the variable should not contain a unit. To have the units
+//! written in the variable too, and checked against those of the quantity, use
+//! `from_env_with_units`, which parses the variable with `FromStr`, in the format that `Display`
+//! prints.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::env::{from_env, from_env_with_units, FromEnvError};
+//! use dim::si;
+//!
+//! fn main() {
+//!     std::env::set_var("EXAMPLE_TRACK_LENGTH", "400");
+//!
+//!     let length = from_env::<si::Meter<f64>>("EXAMPLE_TRACK_LENGTH").unwrap();
+//!     assert_eq!(length, 400.0 * si::M);
+//!
+//!     std::env::set_var("EXAMPLE_TIMEOUT", "2.5 s");
+//!     let timeout = from_env_with_units::<si::Second<f64>>("EXAMPLE_TIMEOUT").unwrap();
+//!     assert_eq!(timeout, 2.5 * si::S);
+//!     assert_eq!(
+//!         from_env_with_units::<si::Meter<f64>>("EXAMPLE_TIMEOUT"),
+//!         Err(FromEnvError::Units)
+//!     );
+//! }
+//! ```
+
+use fmt::ParseQuantityError;
+use std::env::{self, VarError};
+use std::error::Error;
+use std::fmt;
+use std::num::ParseFloatError;
+use std::str::FromStr;
+use Dimensioned;
+
+/// The error given when a quantity cannot be read from an environment variable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FromEnvError {
+    /// The variable is not set.
+    NotPresent,
+    /// The variable is set, but is not valid unicode.
+    NotUnicode,
+    /// The variable is set, but could not be parsed as a number.
+    Invalid(ParseFloatError),
+    /// The variable is set, but its units are not those of the quantity being read.
+    Units,
+}
+
+impl fmt::Display for FromEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            FromEnvError::NotPresent => write!(f, "environment variable not found"),
+            FromEnvError::NotUnicode => write!(f, "environment variable was not valid unicode"),
+            FromEnvError::Invalid(ref e) => {
+                write!(f, "environment variable was not a valid number: {}", e)
+            }
+            FromEnvError::Units => write!(f, "environment variable had unexpected units"),
+        }
+    }
+}
+
+impl Error for FromEnvError {
+    fn description(&self) -> &str {
+        match *self {
+            FromEnvError::NotPresent => "environment variable not found",
+            FromEnvError::NotUnicode => "environment variable was not valid unicode",
+            FromEnvError::Invalid(_) => "environment variable was not a valid number",
+            FromEnvError::Units => "environment variable had unexpected units",
+        }
+    }
+}
+
+impl From<VarError> for FromEnvError {
+    fn from(e: VarError) -> Self {
+        match e {
+            VarError::NotPresent => FromEnvError::NotPresent,
+            VarError::NotUnicode(_) => FromEnvError::NotUnicode,
+        }
+    }
+}
+
+impl From<ParseFloatError> for FromEnvError {
+    fn from(e: ParseFloatError) -> Self {
+        FromEnvError::Invalid(e)
+    }
+}
+
+impl From<ParseQuantityError<ParseFloatError>> for FromEnvError {
+    fn from(e: ParseQuantityError<ParseFloatError>) -> Self {
+        match e {
+            ParseQuantityError::Value(e) => FromEnvError::Invalid(e),
+            ParseQuantityError::Units => FromEnvError::Units,
+        }
+    }
+}
+
+/// Read the environment variable `name` as a number, and give it the units of `Q`.
+///
+/// Whitespace around the number is ignored.
+pub fn from_env<Q>(name: &str) -> Result<Q, FromEnvError>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    let value = env::var(name)?.trim().parse()?;
+    Ok(Q::new(value))
+}
+
+/// Read the environment variable `name` as a quantity with units, such as `2.5 s`, checking that
+/// they are the units of `Q`.
+///
+/// The variable is parsed with `FromStr`, so it must be in the format that `Display` prints, and
+/// whitespace around it is ignored. A bare number is only accepted for a unitless `Q`.
+pub fn from_env_with_units<Q>(name: &str) -> Result<Q, FromEnvError>
+where
+    Q: FromStr<Err = ParseQuantityError<ParseFloatError>>,
+{
+    Ok(env::var(name)?.parse()?)
+}
@@ -0,0 +1,96 @@
+//! Fixed-point representation of quantities
+//!
+//! For deterministic arithmetic, a quantity with an `f64` value can be stored as a quantity with
+//! an `i64` value, scaled by a chosen power of ten. With a scale of `3`, for example, `1.2345 m`
+//! is stored as `1235 m`, counting thousandths of a meter. The units are kept throughout, so it is
+//! up to you to keep track of the scale.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//!
+//! use dim::fixed::{FromFixed, ToFixed};
+//! use dim::si;
+//!
+//! fn main() {
+//!     let x = 1.2345 * si::M;
+//!
+//!     let fixed = x.to_fixed(3).unwrap();
+//!     assert_eq!(fixed, si::Meter::new(1235));
+//!     assert_eq!(fixed.from_fixed(3), 1.235 * si::M);
+//!
+//!     assert!((1e300 * si::M).to_fixed(3).is_err());
+//! }
+//! ```
+
+use core::fmt;
+use num_traits::float::FloatCore;
+
+/// The error given when a value cannot be represented in fixed point, either because it is too
+/// large once scaled, or because it is not finite.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedOutOfRange;
+
+impl fmt::Display for FixedOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "value out of range of the fixed-point representation")
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for FixedOutOfRange {
+    fn description(&self) -> &str {
+        "value out of range of the fixed-point representation"
+    }
+}
+
+/// Convert to a fixed-point representation, storing `self * 10^scale` rounded to the nearest
+/// integer.
+pub trait ToFixed {
+    /// The fixed-point type
+    type Output;
+
+    /// Perform the conversion, failing if the scaled value does not fit in `Output`
+    fn to_fixed(self, scale: i32) -> Result<Self::Output, FixedOutOfRange>;
+}
+
+/// Convert from a fixed-point representation, giving `self * 10^-scale`.
+pub trait FromFixed {
+    /// The floating point type
+    type Output;
+
+    /// Perform the conversion
+    fn from_fixed(self, scale: i32) -> Self::Output;
+}
+
+impl ToFixed for f64 {
+    type Output = i64;
+    fn to_fixed(self, scale: i32) -> Result<Self::Output, FixedOutOfRange> {
+        let factor = FloatCore::powi(10.0f64, scale.abs());
+        let scaled = if scale < 0 {
+            self / factor
+        } else {
+            self * factor
+        };
+        let scaled = FloatCore::round(scaled);
+        // -2^63 is exact as an f64, and 2^63 is the first value too large for an i64
+        let limit = 9_223_372_036_854_775_808.0;
+        if scaled >= -limit && scaled < limit {
+            Ok(scaled as i64)
+        } else {
+            Err(FixedOutOfRange)
+        }
+    }
+}
+
+impl FromFixed for i64 {
+    type Output = f64;
+    fn from_fixed(self, scale: i32) -> Self::Output {
+        let factor = FloatCore::powi(10.0f64, scale.abs());
+        if scale < 0 {
+            self as f64 * factor
+        } else {
+            self as f64 / factor
+        }
+    }
+}
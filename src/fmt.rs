@@ -1,5 +1,662 @@
+//! Configurable printing of quantities
+//!
+//! All of the unit systems that come with dimensioned implement the `core::fmt` traits, printing
+//! a quantity as its value followed by its units, such as `3.5 m*s^-2`. This module provides
+//! `UnitFormat`, a wrapper for printing a quantity's units in other styles.
+//!
+//! They also implement `FromStr`, parsing the same format that `Display` prints, so that a
+//! quantity read from a configuration file can be checked against the units it is expected to
+//! have. Errors are given by `ParseQuantityError`.
+//!
+//! With the `std` feature, `UnitPair` converts a quantity to and from a pair of its value and a
+//! string of its units, in the same format, for code that passes around untyped values.
+
 use core::fmt;
+use core::str::FromStr;
 use typenum::Integer;
+use Dimensioned;
+
+/// Gives the print token and exponent of each base unit of a quantity.
+///
+/// If the units match a derived unit that has its own print token, such as `N` in `SI`, that
+/// token is given alone instead, with an exponent of `1`.
+///
+/// This is implemented by `make_units!` for systems with `fmt = true;`, as well as for the
+/// `CGS`, `MKS`, and `FPS` systems that come with dimensioned.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::fmt::PrintUnits;
+/// use dim::si;
+///
+/// fn main() {
+///     let mut units = Vec::new();
+///     si::NewtonSecond::<f64>::for_each_unit(|token, numer, denom| {
+///         units.push((token, numer, denom));
+///         Ok(())
+///     }).unwrap();
+///
+///     assert_eq!(units, vec![("m", 1, 1), ("kg", 1, 1), ("s", -1, 1)]);
+///
+///     units.clear();
+///     si::Newton::<f64>::for_each_unit(|token, numer, denom| {
+///         units.push((token, numer, denom));
+///         Ok(())
+///     }).unwrap();
+///
+///     assert_eq!(units, vec![("N", 1, 1)]);
+/// }
+/// ```
+pub trait PrintUnits {
+    /// Call `f` with the print token and exponent of each base unit that has a nonzero exponent,
+    /// in order, stopping at the first error.
+    ///
+    /// The exponent is given as a numerator and a denominator. The denominator is `1`, except for
+    /// systems such as `CGS` that have half-integer exponents, where it may be `2`.
+    fn for_each_unit<F>(f: F) -> fmt::Result
+    where
+        F: FnMut(&'static str, isize, isize) -> fmt::Result;
+
+    /// Call `f` as `for_each_unit` does, but always with the base units, even for units that have
+    /// a derived print token, such as the `m`, `kg`, and `s` of a `Newton`.
+    ///
+    /// This defaults to `for_each_unit`, for systems without derived print tokens.
+    fn for_each_base_unit<F>(f: F) -> fmt::Result
+    where
+        F: FnMut(&'static str, isize, isize) -> fmt::Result,
+    {
+        Self::for_each_unit(f)
+    }
+}
+
+/// The error given when parsing a quantity from a string fails.
+///
+/// A quantity is parsed from its value, then whitespace, then its units, written as `Display` would
+/// print them. A unitless quantity is just its value. Whitespace around the whole is ignored.
+/// The units may instead be written as the base units, in any order, so a force may be parsed
+/// from `5 N` or from `5 kg*m*s^-2`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::fmt::ParseQuantityError;
+/// use dim::si;
+///
+/// fn main() {
+///     let g: si::MeterPerSecond2<f64> = "9.81 m*s^-2".parse().unwrap();
+///     assert_eq!(g, 9.81 * si::MPS2);
+///     assert_eq!("5 N".parse(), Ok(5.0 * si::N));
+///     assert_eq!("5 kg*m*s^-2".parse(), Ok(5.0 * si::N));
+///
+///     assert_eq!("5 s".parse::<si::Meter<f64>>(), Err(ParseQuantityError::Units));
+///     assert!(match "five m".parse::<si::Meter<f64>>() {
+///         Err(ParseQuantityError::Value(_)) => true,
+///         _ => false,
+///     });
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseQuantityError<E> {
+    /// The value could not be parsed; this holds the error from parsing it.
+    Value(E),
+    /// The units were not those of the quantity being parsed.
+    Units,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseQuantityError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseQuantityError::Value(ref e) => write!(f, "invalid value: {}", e),
+            ParseQuantityError::Units => write!(f, "unexpected units"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: ::std::error::Error> ::std::error::Error for ParseQuantityError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ParseQuantityError::Value(_) => "invalid value",
+            ParseQuantityError::Units => "unexpected units",
+        }
+    }
+}
+
+/// Matches written units against a string, failing at the first difference
+struct MatchUnits<'a> {
+    rest: &'a str,
+}
+
+impl<'a> fmt::Write for MatchUnits<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.rest.starts_with(s) {
+            self.rest = &self.rest[s.len()..];
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// Write the units of `Q` as its `Display` prints them
+fn write_units<Q: PrintUnits, W: fmt::Write>(w: &mut W) -> fmt::Result {
+    let mut first = true;
+    Q::for_each_unit(|token, numer, denom| {
+        if !first {
+            w.write_str("*")?;
+        }
+        first = false;
+        w.write_str(token)?;
+        if numer == denom {
+            Ok(())
+        } else if denom == 1 {
+            write!(w, "^{}", numer)
+        } else {
+            write!(w, "^{}", numer as f32 / denom as f32)
+        }
+    })
+}
+
+/// Whether `units` are the units of `Q`, as its `Display` prints them
+fn units_match<Q: PrintUnits>(units: &str) -> bool {
+    let mut matcher = MatchUnits { rest: units };
+    write_units::<Q, _>(&mut matcher).is_ok() && matcher.rest.is_empty()
+}
+
+/// Whether `units` are the base units of `Q`, in any order, as in `kg*m*s^-2` for a newton
+fn base_units_match<Q: PrintUnits>(units: &str) -> bool {
+    let pieces = if units.is_empty() {
+        0
+    } else {
+        units.split('*').count()
+    };
+    let mut expected = 0;
+    let matched = Q::for_each_base_unit(|token, numer, denom| {
+        expected += 1;
+        let exp = numer as f32 / denom as f32;
+        let found = units.split('*').any(|piece| {
+            let mut parts = piece.splitn(2, '^');
+            parts.next() == Some(token)
+                && match parts.next() {
+                    None => numer == denom,
+                    Some(e) => e.parse() == Ok(exp),
+                }
+        });
+        if found {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    });
+    // Each base unit has a distinct token, so each piece matches at most one of them
+    matched.is_ok() && expected == pieces
+}
+
+// Used for the make_units macro, to implement `FromStr`
+#[doc(hidden)]
+pub fn parse_quantity<Q, V>(s: &str) -> Result<V, ParseQuantityError<V::Err>>
+where
+    Q: PrintUnits,
+    V: FromStr,
+{
+    let s = s.trim();
+    let (value, units) = match s.find(char::is_whitespace) {
+        Some(i) => (&s[..i], s[i..].trim()),
+        None => (s, ""),
+    };
+    let value = value.parse().map_err(ParseQuantityError::Value)?;
+    if units_match::<Q>(units) || base_units_match::<Q>(units) {
+        Ok(value)
+    } else {
+        Err(ParseQuantityError::Units)
+    }
+}
+
+/// `UnitPair` is used for converting a quantity to and from a pair of its value and its units, for
+/// passing it through code that doesn't know about dimensioned.
+///
+/// The units are written as `Display` prints them. When converting back, they are checked against
+/// the units of the quantity, as with `FromStr`, and may also be written as base units.
+///
+/// It is implemented for every quantity with a value type of `f64` that implements `PrintUnits`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::fmt::UnitPair;
+/// use dim::si;
+///
+/// fn main() {
+///     let a = 9.8 * si::MPS2;
+///     assert_eq!(a.to_pair(), (9.8, "m*s^-2".to_string()));
+///     assert_eq!(si::MeterPerSecond2::from_pair((9.8, "m*s^-2")), Some(a));
+///
+///     assert_eq!(si::Meter::<f64>::from_pair((9.8, "s")), None);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub trait UnitPair: Sized {
+    /// Give the value of this quantity and its units.
+    fn to_pair(&self) -> (f64, String);
+
+    /// Make a quantity from its value and its units, or give `None` if the units are not those
+    /// of `Self`.
+    fn from_pair(pair: (f64, &str)) -> Option<Self>;
+}
+
+#[cfg(feature = "std")]
+impl<Q> UnitPair for Q
+where
+    Q: Dimensioned<Value = f64> + PrintUnits,
+{
+    fn to_pair(&self) -> (f64, String) {
+        let mut units = String::new();
+        write_units::<Q, _>(&mut units).expect("writing to a String cannot fail");
+        (*self.value_unsafe(), units)
+    }
+
+    fn from_pair((value, units): (f64, &str)) -> Option<Self> {
+        let units = units.trim();
+        if units_match::<Q>(units) || base_units_match::<Q>(units) {
+            Some(Q::new(value))
+        } else {
+            None
+        }
+    }
+}
+
+/// How to group the units when printing with `UnitFormat`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Grouping {
+    /// Print every unit with its signed exponent, as in `m*s^-2`.
+    Exponents,
+    /// Print units with negative exponents as a denominator, as in `m/s^2` or `kg/(m*s^2)`.
+    Fraction,
+}
+
+/// How to print exponents with `UnitFormat`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExponentStyle {
+    /// Use a caret, as in `m^2`.
+    Caret,
+    /// Use Unicode superscripts, as in `m²`.
+    Unicode,
+    /// Use LaTeX superscripts, as in `m^{2}`.
+    Latex,
+}
+
+/// A wrapper for printing a quantity with configurable units.
+///
+/// It implements `Display`, printing the value with any flags given, such as precision, followed
+/// by its units. Without any configuration, it prints the same as the `Display` of the quantity.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::fmt::{ExponentStyle, Grouping, UnitFormat};
+/// use dim::si;
+///
+/// fn main() {
+///     let a = 9.8 * si::MPS2;
+///     assert_eq!(format!("{}", UnitFormat::new(a)), "9.8 m*s^-2");
+///
+///     let fraction = UnitFormat::new(a).grouping(Grouping::Fraction);
+///     assert_eq!(format!("{:.0}", fraction), "10 m/s^2");
+///
+///     let styled = UnitFormat::new(a)
+///         .separator("·")
+///         .exponents(ExponentStyle::Unicode);
+///     assert_eq!(format!("{}", styled), "9.8 m·s⁻²");
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct UnitFormat<Q> {
+    quantity: Q,
+    grouping: Grouping,
+    separator: &'static str,
+    exponents: ExponentStyle,
+    reciprocal_one: bool,
+    thousands: Option<&'static str>,
+}
+
+impl<Q> UnitFormat<Q> {
+    /// Wrap `quantity` for printing, with the same style as its `Display`.
+    pub fn new(quantity: Q) -> Self {
+        UnitFormat {
+            quantity,
+            grouping: Grouping::Exponents,
+            separator: "*",
+            exponents: ExponentStyle::Caret,
+            reciprocal_one: true,
+            thousands: None,
+        }
+    }
+
+    /// Set how units are grouped. Defaults to `Grouping::Exponents`.
+    pub fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Set the symbol printed between units. Defaults to `*`.
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set how exponents are printed. Defaults to `ExponentStyle::Caret`.
+    pub fn exponents(mut self, exponents: ExponentStyle) -> Self {
+        self.exponents = exponents;
+        self
+    }
+
+    /// Set whether units with only negative exponents print as a fraction with numerator `1`,
+    /// as in `1/s`, when using `Grouping::Fraction`. If not, they print with negative exponents,
+    /// as with `Grouping::Exponents`. Defaults to `true`.
+    pub fn reciprocal_one(mut self, reciprocal_one: bool) -> Self {
+        self.reciprocal_one = reciprocal_one;
+        self
+    }
+
+    /// Group the digits of the integer part of the value in threes with `separator`, as in
+    /// `1,500,000 m`. Defaults to no grouping.
+    ///
+    /// When grouping, the only formatting flag passed on to the value is its precision.
+    pub fn thousands(mut self, separator: &'static str) -> Self {
+        self.thousands = Some(separator);
+        self
+    }
+
+    /// Unwrap the quantity.
+    pub fn into_inner(self) -> Q {
+        self.quantity
+    }
+
+    fn write_exponent(&self, f: &mut fmt::Formatter, numer: isize, denom: isize) -> fmt::Result {
+        if numer == denom {
+            return Ok(());
+        }
+        match self.exponents {
+            ExponentStyle::Caret if denom == 1 => write!(f, "^{}", numer),
+            ExponentStyle::Caret => write!(f, "^{}", numer as f32 / denom as f32),
+            ExponentStyle::Unicode => {
+                write_superscript(f, numer)?;
+                if denom != 1 {
+                    write!(f, "ᐟ")?;
+                    write_superscript(f, denom)?;
+                }
+                Ok(())
+            }
+            ExponentStyle::Latex if denom == 1 => write!(f, "^{{{}}}", numer),
+            ExponentStyle::Latex => write!(f, "^{{{}/{}}}", numer, denom),
+        }
+    }
+}
+
+/// Counts the digits of the integer part of a formatted number
+struct IntegerDigits {
+    count: usize,
+    done: bool,
+}
+
+impl fmt::Write for IntegerDigits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.done {
+                break;
+            }
+            match c {
+                _ if c.is_digit(10) => self.count += 1,
+                '-' | '+' if self.count == 0 => (),
+                _ => self.done = true,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Passes a formatted number through, inserting a separator between groups of three digits of
+/// its integer part
+struct Grouper<'a, 'b: 'a> {
+    f: &'a mut fmt::Formatter<'b>,
+    separator: &'static str,
+    remaining: usize,
+    started: bool,
+}
+
+impl<'a, 'b> fmt::Write for Grouper<'a, 'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.remaining > 0 && c.is_digit(10) {
+                if self.started && self.remaining % 3 == 0 {
+                    self.f.write_str(self.separator)?;
+                }
+                self.started = true;
+                self.remaining -= 1;
+            } else if self.started {
+                self.remaining = 0;
+            }
+            fmt::Write::write_char(self.f, c)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_grouped<V: fmt::Display>(
+    f: &mut fmt::Formatter,
+    value: &V,
+    separator: &'static str,
+) -> fmt::Result {
+    fn write_value<W: fmt::Write, V: fmt::Display>(
+        w: &mut W,
+        value: &V,
+        precision: Option<usize>,
+    ) -> fmt::Result {
+        match precision {
+            Some(precision) => write!(w, "{:.*}", precision, value),
+            None => write!(w, "{}", value),
+        }
+    }
+
+    let precision = f.precision();
+    let mut digits = IntegerDigits {
+        count: 0,
+        done: false,
+    };
+    write_value(&mut digits, value, precision)?;
+
+    let mut grouper = Grouper {
+        f,
+        separator,
+        remaining: digits.count,
+        started: false,
+    };
+    write_value(&mut grouper, value, precision)
+}
+
+/// Records a formatted number, failing as soon as it is clear that it is not negative zero
+struct NegativeZero {
+    buf: [u8; 40],
+    len: usize,
+}
+
+impl fmt::Write for NegativeZero {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for &b in s.as_bytes() {
+            let allowed = match self.len {
+                0 => b == b'-',
+                1 => b == b'0',
+                _ => b == b'0' || b == b'.' || b == b'e' || b == b'E',
+            };
+            if !allowed || self.len == self.buf.len() {
+                return Err(fmt::Error);
+            }
+            self.buf[self.len] = b;
+            self.len += 1;
+        }
+        Ok(())
+    }
+}
+
+// Used for the make_units macro, so that `Display` prints `-0.0 * m` as `0 m`
+#[doc(hidden)]
+pub fn display_value<V: fmt::Display>(f: &mut fmt::Formatter, value: &V) -> fmt::Result {
+    use core::fmt::Write;
+
+    let mut zero = NegativeZero {
+        buf: [0; 40],
+        len: 0,
+    };
+    let is_negative_zero = match f.precision() {
+        Some(precision) => write!(zero, "{:.*}", precision, value),
+        None => write!(zero, "{}", value),
+    }
+    .is_ok()
+        && zero.len > 1;
+
+    if is_negative_zero {
+        // Only ASCII was recorded, so this cannot fail
+        if let Ok(unsigned) = ::core::str::from_utf8(&zero.buf[1..zero.len]) {
+            return f.pad_integral(true, "", unsigned);
+        }
+    }
+    value.fmt(f)
+}
+
+// Used for the make_units macro and CGS-like systems, when formatting with the alternate flag
+#[doc(hidden)]
+pub fn write_superscript(f: &mut fmt::Formatter, n: isize) -> fmt::Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    if n < 0 {
+        write!(f, "⁻")?;
+    }
+    let mut magnitude = n.abs();
+    let mut place = 1;
+    while magnitude / place >= 10 {
+        place *= 10;
+    }
+    while place > 0 {
+        write!(f, "{}", DIGITS[(magnitude / place) as usize])?;
+        magnitude %= place;
+        place /= 10;
+    }
+    Ok(())
+}
+
+impl<Q> fmt::Display for UnitFormat<Q>
+where
+    Q: Dimensioned + PrintUnits,
+    Q::Value: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.thousands {
+            Some(separator) => write_grouped(f, self.quantity.value_unsafe(), separator)?,
+            None => display_value(f, self.quantity.value_unsafe())?,
+        }
+
+        let (mut numerators, mut denominators) = (0, 0);
+        Q::for_each_unit(|_, numer, _| {
+            if numer > 0 {
+                numerators += 1;
+            } else {
+                denominators += 1;
+            }
+            Ok(())
+        })?;
+
+        if numerators + denominators == 0 {
+            return Ok(());
+        }
+        write!(f, " ")?;
+
+        let fraction = self.grouping == Grouping::Fraction
+            && denominators > 0
+            && (numerators > 0 || self.reciprocal_one);
+
+        if !fraction {
+            let mut first = true;
+            return Q::for_each_unit(|token, numer, denom| {
+                if !first {
+                    write!(f, "{}", self.separator)?;
+                }
+                first = false;
+                write!(f, "{}", token)?;
+                self.write_exponent(f, numer, denom)
+            });
+        }
+
+        if numerators == 0 {
+            write!(f, "1")?;
+        }
+        let mut first = true;
+        Q::for_each_unit(|token, numer, denom| {
+            if numer > 0 {
+                if !first {
+                    write!(f, "{}", self.separator)?;
+                }
+                first = false;
+                write!(f, "{}", token)?;
+                self.write_exponent(f, numer, denom)?;
+            }
+            Ok(())
+        })?;
+
+        write!(f, "/")?;
+        if denominators > 1 {
+            write!(f, "(")?;
+        }
+        let mut first = true;
+        Q::for_each_unit(|token, numer, denom| {
+            if numer < 0 {
+                if !first {
+                    write!(f, "{}", self.separator)?;
+                }
+                first = false;
+                write!(f, "{}", token)?;
+                self.write_exponent(f, -numer, denom)?;
+            }
+            Ok(())
+        })?;
+        if denominators > 1 {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! print_units_cgs_like {
+    ($System:ident; $tokens:expr) => {
+        impl<V, U1, U2, U3> PrintUnits for $System<V, tarr![U1, U2, U3]>
+        where
+            U1: Integer,
+            U2: Integer,
+            U3: Integer,
+        {
+            fn for_each_unit<F>(mut f: F) -> fmt::Result
+            where
+                F: FnMut(&'static str, isize, isize) -> fmt::Result,
+            {
+                // double U3 so we can treat them all the same, as sqrts
+                let exponents = [U1::to_isize(), U2::to_isize(), U3::to_isize() * 2];
+                let print_tokens = $tokens;
+
+                for (&exp, &token) in exponents.iter().zip(print_tokens.iter()) {
+                    match exp {
+                        0 => (),
+                        _ if exp % 2 == 0 => f(token, exp / 2, 1)?,
+                        _ => f(token, exp, 2)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+}
 
 macro_rules! format_cgs_like {
     ($System:ident; $tokens:expr; $($Trait:ident)*) => (
@@ -12,7 +669,7 @@ macro_rules! format_cgs_like {
                 let exponents = [U1::to_isize(), U2::to_isize(), U3::to_isize()*2];
                 let print_tokens = $tokens;
 
-                self.value_unsafe.fmt(f)?;
+                __make_units_internal!(@fmt_value $Trait f self.value_unsafe)?;
 
                 let mut first = true;
                 for (&exp, token) in
@@ -31,6 +688,16 @@ macro_rules! format_cgs_like {
                     match exp {
                         0 => (),
                         2 => write!(f, "{}", token)?,
+                        _ if f.alternate() => {
+                            write!(f, "{}", token)?;
+                            if exp % 2 == 0 {
+                                write_superscript(f, exp/2)?;
+                            } else {
+                                // Halves print as a superscript fraction, like `cm³ᐟ²`
+                                write_superscript(f, exp)?;
+                                write!(f, "ᐟ²")?;
+                            }
+                        },
                         _ if exp % 2 == 0 => write!(f, "{}^{}", token, exp/2)?,
                         _ => write!(f, "{}^{}", token, (exp as f32)/2.0)?,
                     }
@@ -43,9 +710,12 @@ macro_rules! format_cgs_like {
 
 use unit_systems::cgs::CGS;
 format_cgs_like!(CGS; ["cm", "g", "s"]; Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+print_units_cgs_like!(CGS; ["cm", "g", "s"]);
 
 use unit_systems::mks::MKS;
 format_cgs_like!(MKS; ["m", "k", "s"]; Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+print_units_cgs_like!(MKS; ["m", "k", "s"]);
 
 use unit_systems::fps::FPS;
 format_cgs_like!(FPS; ["ft", "lb", "s"]; Display Octal LowerHex UpperHex Pointer Binary LowerExp UpperExp);
+print_units_cgs_like!(FPS; ["ft", "lb", "s"]);
@@ -78,6 +78,44 @@ defined, and results in a normal multiplication of the value types, and the unit
 So, multiplying `Meter<f64>` by `Second<f64>` gives `SI<f64, tarr![P1, Z0, P1, Z0, Z0, Z0,
 Z0]>`.
 
+Comparisons work like addition. Quantities with the same units can be compared, such as with `<`,
+which compares their values, but comparing a length to a time is a compiler error:
+
+```rust,compile_fail
+extern crate dimensioned as dim;
+use dim::si;
+
+fn main() {
+    let shorter = 1.0 * si::M < 2.0 * si::S;
+}
+```
+
+Compound assignment is the same; `x += y` and `x -= y` need `x` and `y` to have the same units,
+while `x *= y` and `x /= y` need `y` to be a bare value, as anything else would change the units
+of `x`:
+
+```rust,compile_fail
+extern crate dimensioned as dim;
+use dim::si;
+
+fn main() {
+    let mut x = 1.0 * si::M;
+    x += 2.0 * si::S;
+}
+```
+
+The remainder `x % y` also needs `y` to have the same units as `x`, or to be a bare value, and
+keeps the units of `x`:
+
+```rust,compile_fail
+extern crate dimensioned as dim;
+use dim::si;
+
+fn main() {
+    let r = 7.0 * si::S % (3.0 * si::M);
+}
+```
+
 ## Example
 
 ```rust
@@ -183,23 +221,38 @@ extern crate serde_test;
 #[cfg(feature = "rand")]
 pub extern crate rand;
 
+#[cfg(feature = "quickcheck")]
+pub extern crate quickcheck;
+
 #[macro_use]
 mod make_units;
-mod fmt;
+pub mod fmt;
 
 include!(concat!(env!("OUT_DIR"), "/unit_systems.rs"));
+pub mod affine;
 pub mod array;
 pub mod conversion;
 pub mod dimensions;
+pub mod display_units;
+#[cfg(feature = "std")]
+pub mod env;
 pub mod f32prefixes;
 pub mod f64prefixes;
+pub mod fixed;
+pub mod numeric;
+pub mod physics;
+#[cfg(all(feature = "serde", feature = "std"))]
+pub mod serde_units;
+pub mod stats;
+#[cfg(feature = "quickcheck")]
+pub mod test_util;
 pub mod traits;
 
 pub use traits::*;
-pub use unit_systems::{cgs, fps, mks, si, ucum};
+pub use unit_systems::{angle, cgs, fps, imperial, mks, si, ucum};
 
 // Used for the make_units macro
 #[doc(hidden)]
 pub mod dimcore {
-    pub use core::{f32, f64, fmt, marker, mem, ops};
+    pub use core::{cmp, convert, f32, f64, fmt, marker, mem, ops, str};
 }
@@ -26,7 +26,7 @@ pub mod ms {
 
         derived {
             MPS: MeterPerSecond = (Meter / Second), Velocity;
-            HZ: Hertz = (Unitless / Second), Frequency;
+            HZ: Hertz = (Unitless / Second) as "Hz", Frequency;
 
             M3: Meter3 = (Meter * Meter * Meter), Volume;
             M5: Meter5 = (Meter3 * Meter * Meter);
@@ -90,13 +90,18 @@ In the `derived` block, we can make derived units from our base units. The begin
 have `CONST: Type`. After the equal signs, we have a formula to define this unit. The parentheses
 are required, and the only things that can be inside them are the names of other units, and the *
 and / operators. Hopefully, this part of the macro will be made more flexibile in the
-future. Finally, we again end with an optional dimension. Note that there is none present for the
-`M5` line.
+future. Next is an optional print token, given after `as`. A quantity whose units match this
+derived unit exactly will print with that token, so `2.0 * HZ` prints as `2 Hz` rather than
+`2 s^-1`; derived units without one print in terms of the base units. Only an exact match is
+used: units are not factored into derived and base units, so with a token of `"J"` for joules,
+`N * M` prints as `J` but `J / K` prints in base units, not as `J*K^-1`. If several derived units
+with tokens have the same units, the first one listed is used. Finally, we again end with an
+optional dimension. Note that there is none present for the `M5` line.
 
 ```ignore
         derived {
             MPS: MeterPerSecond = (Meter / Second), Velocity;
-            HZ: Hertz = (Unitless / Second), Frequency;
+            HZ: Hertz = (Unitless / Second) as "Hz", Frequency;
 
             M3: Meter3 = (Meter * Meter * Meter), Volume;
             M5: Meter5 = (Meter3 * Meter * Meter);
@@ -115,6 +120,10 @@ constants for base and derived units. The full list of integer modules is `i8con
 `i32consts`, `i64consts`, `isize_consts`, `u8consts`, `u16consts`, `u32consts`, `u64consts`,
 `usize_consts`.
 
+The unit system itself is generic over its value type, so for example `Meter<f32>` and
+`Newton<i64>` support the same operations and formatting as `Meter<f64>`, as far as the value type
+supports them; only the constants are limited to the modules above.
+
 If you would like non-unary integer constants, you will have to construct them yourself, like so
 
 ```rust
@@ -130,6 +139,10 @@ Support for making this better is in the works.
 In these submodules, the consts from the respective version of `f32prefixes` or `f64prefixes` are in
 scope, hence the use of `CENTI` in the `CM` definition.
 
+This is also how to give a system prefixed units. The prefixed constants of the systems that ship
+with dimensioned, such as `si::KM`, are written by its build script rather than by this macro, so
+a system made with it has only the prefixed constants that are defined here, one per prefix.
+
 In addition, the respective version of `core::f32::consts` or `core::f64::consts` is in scope, which
 allows the use of `consts::PI` in the `PI definition.
 
@@ -150,6 +163,14 @@ case, the trait `core::fmt::Debug` is implemented for your unit system, but all
 traits are implemented only if this is true. Setting it to `false` allows you to have custom
 printing for your system.
 
+The exponents of units print like `m^2` and `s^-2`. With the alternate flag, as in
+`format!("{:#}", x)`, they print with Unicode superscripts instead, like `m²` and `s⁻²`. The flag is
+also passed on to the value, so for example `{:#x}` prints both the `0x` prefix and superscripts.
+
+`Display` prints a value of negative zero as zero, so `-0.0 * M` prints as `0 m`, as does a small
+negative value rounded to zero by a precision, such as `-0.01 * M` with `{:.1}`. The other traits,
+including `Debug`, print the value as it is.
+
 ```ignore
         fmt = true;
     }
@@ -181,7 +202,8 @@ macro_rules! make_units {
          $($base:ident: $Unit:ident, $print_as:expr $(, $base_dim:ident)*;)+
      }
      derived {
-         $($derived_const:ident: $Derived:ident = ($($derived_rhs:tt)+) $(, $derived_dim:ident)*;)*
+         $($derived_const:ident: $Derived:ident = ($($derived_rhs:tt)+) $(as $derived_print_as:tt)*
+           $(, $derived_dim:ident)*;)*
      }
      constants {
          $($constant:ident: $ConstantUnit:ident = $constant_value:expr;)*
@@ -192,7 +214,12 @@ macro_rules! make_units {
         use $crate::{Dimensioned, Dimensionless};
 
         /// The struct for this unit system
-        #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash)]
+        ///
+        /// Quantities are `Eq`, `Ord`, and `Hash` whenever their value types are. The primitive
+        /// floats are not, but a totally ordered wrapper, such as `OrderedFloat` from the
+        /// `ordered-float` crate, may be used as the value type to use quantities as the keys of a
+        /// `BTreeMap` or `BTreeSet`. Arithmetic works as it does for any other value type.
+        #[derive(Eq, Ord, Clone, Copy, Hash)]
         pub struct $System<V, U> {
             /// This is the value of whatever type we're giving units. Using it directly bypasses
             /// all of the dimensional analysis that having a unit system provides, and should be
@@ -221,11 +248,157 @@ macro_rules! make_units {
         impl<V, U> $System<V, U> {
 
             /// Create a new quantity in the $System unit system
+            ///
+            /// This is the constructor to use when building a quantity from a bare value, such as
+            /// the result of a conversion. The units may be named by a type alias, as in
+            /// `Meter::new(v)`, or given explicitly, as in `$System::<_, U>::new(v)`, rather
+            /// than relying on the public fields.
             #[inline]
             pub fn new(v: V) -> Self {
                 $System { value_unsafe: v, _marker: marker::PhantomData }
             }
+
+            /// Borrow the value of a quantity.
+            ///
+            /// Like the `value_unsafe` field, this ignores the units, so it is best kept for
+            /// handing a value to code that does not know about them, such as a plotting library.
+            #[inline]
+            pub fn value(&self) -> &V {
+                &self.value_unsafe
+            }
+
+            /// Mutably borrow the value of a quantity, ignoring its units.
+            #[inline]
+            pub fn value_mut(&mut self) -> &mut V {
+                &mut self.value_unsafe
+            }
+
+            /// Consume a quantity and give its value, ignoring its units.
+            #[inline]
+            pub fn into_inner(self) -> V {
+                self.value_unsafe
+            }
+
+            /// Give the lesser of two quantities with the same units.
+            ///
+            /// If they are not comparable, such as when either value is `NaN`, `self` is given.
+            ///
+            /// For unitless quantities, this, `max`, and `clamp` take the place of the methods
+            /// of the value reached through `Deref`, so the bound must be unitless too. To compare
+            /// with a bare value instead, dereference the quantity first, as in `(*ratio).min(1.0)`.
+            #[inline]
+            pub fn min(self, other: Self) -> Self where V: PartialOrd {
+                if other.value_unsafe < self.value_unsafe { other } else { self }
+            }
+
+            /// Give the greater of two quantities with the same units.
+            ///
+            /// If they are not comparable, such as when either value is `NaN`, `self` is given.
+            #[inline]
+            pub fn max(self, other: Self) -> Self where V: PartialOrd {
+                if other.value_unsafe > self.value_unsafe { other } else { self }
+            }
+
+            /// Restrict a quantity to the range `[lo, hi]`, where the bounds have the same units.
+            ///
+            /// This calls `numeric::clamp`, so a `NaN` is returned unchanged.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `lo > hi`, or if either bound is `NaN`, as `f64::clamp` does.
+            #[inline]
+            pub fn clamp(self, lo: Self, hi: Self) -> Self where V: PartialOrd {
+                $crate::numeric::clamp(self, lo, hi)
+            }
+
+            /// Apply a function to the value of a quantity, keeping its units.
+            ///
+            /// The units cannot change, so this is for operations that make sense for a value in
+            /// any units, such as rounding or clamping. Something like squaring the value does not
+            /// keep its units, and is a job for `MapUnsafe`.
+            #[inline]
+            pub fn map<VOut, F: FnOnce(V) -> VOut>(self, f: F) -> $System<VOut, U> {
+                $System::new(f(self.value_unsafe))
+            }
+
+            /// Raise a quantity to the power `Exp`, which is a type-level integer such as `P3`.
+            ///
+            /// This is equivalent to `Pow::powi`, but the exponent is given as a type parameter
+            /// rather than as an instantiated type number, as in `x.pow_p::<P3>()`.
+            #[inline]
+            pub fn pow_p<Exp>(self) -> <Self as $crate::typenum::Pow<Exp>>::Output
+                where Self: $crate::typenum::Pow<Exp>, Exp: Default,
+            {
+                $crate::typenum::Pow::powi(self, Exp::default())
+            }
+
+            /// Raise a quantity to the rational power `Num/Den`, where both are type-level
+            /// integers, as in `x.pow_ratio::<P2, P3>()` for `x^(2/3)`.
+            ///
+            /// This is `Pow` followed by `Root`, so the units are raised to the power `Num` and
+            /// then must be evenly divisible by `Den`, or it will not compile. The value is
+            /// likewise computed as `(v^Num)^(1/Den)`.
+            #[inline]
+            pub fn pow_ratio<Num, Den>(self)
+                -> <<Self as $crate::typenum::Pow<Num>>::Output as $crate::Root<Den>>::Output
+                where Self: $crate::typenum::Pow<Num>,
+                      <Self as $crate::typenum::Pow<Num>>::Output: $crate::Root<Den>,
+                      Num: Default, Den: Default,
+            {
+                $crate::Root::root($crate::typenum::Pow::powi(self, Num::default()), Den::default())
+            }
+
+            /// Change the type of the units of a quantity to `UOut`, which must be the same units.
+            ///
+            /// This is free at runtime. It is useful in generic code, where the compiler may not
+            /// be able to tell that two unit types are equal until they are known; see `SameUnits`.
+            #[inline]
+            pub fn cast_units<UOut>(self) -> $System<V, UOut> where U: $crate::SameUnits<UOut> {
+                $System::new(self.value_unsafe)
+            }
+        }
+
+        // --------------------------------------------------------------------------------
+        // Comparison
+        //
+        // These are implemented by hand, rather than derived, so that quantities with the same
+        // units may be compared even if their value types differ.
+
+        use $crate::dimcore::cmp::{Ordering, PartialEq, PartialOrd};
+        impl<Vl, Vr, U> PartialEq<$System<Vr, U>> for $System<Vl, U> where Vl: PartialEq<Vr> {
+            #[inline]
+            fn eq(&self, other: &$System<Vr, U>) -> bool {
+                self.value_unsafe == other.value_unsafe
+            }
+        }
+
+        impl<Vl, Vr, U> PartialOrd<$System<Vr, U>> for $System<Vl, U> where Vl: PartialOrd<Vr> {
+            #[inline]
+            fn partial_cmp(&self, other: &$System<Vr, U>) -> Option<Ordering> {
+                self.value_unsafe.partial_cmp(&other.value_unsafe)
+            }
+        }
+
+        // Unitless quantities, and only those, may also be compared with bare floats
+        macro_rules! impl_unitless_eq {
+            ($t:ty) => (
+                impl PartialEq<$t> for $Unitless<$t> {
+                    #[inline]
+                    fn eq(&self, other: &$t) -> bool {
+                        self.value_unsafe == *other
+                    }
+                }
+
+                impl PartialEq<$Unitless<$t>> for $t {
+                    #[inline]
+                    fn eq(&self, other: &$Unitless<$t>) -> bool {
+                        *self == other.value_unsafe
+                    }
+                }
+            );
         }
+        impl_unitless_eq!(f32);
+        impl_unitless_eq!(f64);
 
         // --------------------------------------------------------------------------------
         // Implement traits defined in dim::traits
@@ -298,6 +471,98 @@ macro_rules! make_units {
             }
         }
 
+        // Only unitless quantities may be turned back into bare numbers
+        macro_rules! impl_from_unitless {
+            ($t:ty) => (
+                impl From<$Unitless<$t>> for $t {
+                    #[inline]
+                    fn from(x: $Unitless<$t>) -> $t {
+                        x.value_unsafe
+                    }
+                }
+            );
+        }
+        impl_from_unitless!(f32);
+        impl_from_unitless!(f64);
+
+        impl_from_unitless!(i8);
+        impl_from_unitless!(i16);
+        impl_from_unitless!(i32);
+        impl_from_unitless!(i64);
+        impl_from_unitless!(isize);
+
+        impl_from_unitless!(u8);
+        impl_from_unitless!(u16);
+        impl_from_unitless!(u32);
+        impl_from_unitless!(u64);
+        impl_from_unitless!(usize);
+
+        // Trigonometry, arbitrary powers, and logarithms only make sense for unitless quantities, so
+        // these are implemented for `$Unitless` alone, shadowing the methods that would be reached
+        // through `Deref`.
+        macro_rules! impl_unitless_float {
+            ($t:ident) => (
+                #[cfg(feature = "std")]
+                impl $Unitless<$t> {
+                    /// Compute the sine of an angle, in radians.
+                    #[inline]
+                    pub fn sin(self) -> Self { $System::new(self.value_unsafe.sin()) }
+                    /// Compute the cosine of an angle, in radians.
+                    #[inline]
+                    pub fn cos(self) -> Self { $System::new(self.value_unsafe.cos()) }
+                    /// Compute the tangent of an angle, in radians.
+                    #[inline]
+                    pub fn tan(self) -> Self { $System::new(self.value_unsafe.tan()) }
+                    /// Compute the arcsine, in radians in the range `[-pi/2, pi/2]`.
+                    #[inline]
+                    pub fn asin(self) -> Self { $System::new(self.value_unsafe.asin()) }
+                    /// Compute the arccosine, in radians in the range `[0, pi]`.
+                    #[inline]
+                    pub fn acos(self) -> Self { $System::new(self.value_unsafe.acos()) }
+                    /// Compute the arctangent, in radians in the range `[-pi/2, pi/2]`.
+                    #[inline]
+                    pub fn atan(self) -> Self { $System::new(self.value_unsafe.atan()) }
+
+                    /// Raise to the power `exp`, which need not be an integer.
+                    ///
+                    /// The units of a quantity with units raised to an arbitrary power can't be
+                    /// known at compile time, so for those, see `pow_p` and `pow_ratio` instead.
+                    #[inline]
+                    pub fn powf(self, exp: $t) -> Self {
+                        $System::new(self.value_unsafe.powf(exp))
+                    }
+
+                    /// Compute `e^self`.
+                    #[inline]
+                    pub fn exp(self) -> Self { $System::new(self.value_unsafe.exp()) }
+                    /// Compute the natural logarithm.
+                    #[inline]
+                    pub fn ln(self) -> Self { $System::new(self.value_unsafe.ln()) }
+                    /// Compute the base 10 logarithm.
+                    #[inline]
+                    pub fn log10(self) -> Self { $System::new(self.value_unsafe.log10()) }
+                    /// Compute the base 2 logarithm.
+                    #[inline]
+                    pub fn log2(self) -> Self { $System::new(self.value_unsafe.log2()) }
+                }
+
+                #[cfg(feature = "std")]
+                impl<U> $System<$t, U> {
+                    /// Compute the four quadrant arctangent of `self` (`y`) and `other` (`x`), in
+                    /// radians in the range `[-pi, pi]`.
+                    ///
+                    /// Both must have the same units, but they need not be unitless, as only their
+                    /// ratio matters.
+                    #[inline]
+                    pub fn atan2(self, other: Self) -> $Unitless<$t> {
+                        $System::new(self.value_unsafe.atan2(other.value_unsafe))
+                    }
+                }
+            );
+        }
+        impl_unitless_float!(f32);
+        impl_unitless_float!(f64);
+
         $(#[allow(missing_docs)] pub type $Derived<V> = $System<V, inner::$Derived>;
           $(impl<V> $crate::dimensions::$derived_dim for $Derived<V> {})*
         )*
@@ -368,15 +633,29 @@ macro_rules! make_units {
         use $crate::generic_array::{GenericArray, ArrayLength};
         use $crate::array::ToGA;
 
-        __make_units_internal!(@fmt true S $System $(P $print_as;)* T Debug E "{:?}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T Display E "{}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T Octal E "{:o}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T LowerHex E "{:x}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T UpperHex E "{:X}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T Pointer E "{:p}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T Binary E "{:b}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T LowerExp E "{:e}");
-        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* T UpperExp E "{:E}");
+        __make_units_internal!(@fmt true S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T Debug E "{:?}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T Display E "{}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T Octal E "{:o}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T LowerHex E "{:x}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T UpperHex E "{:X}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T Pointer E "{:p}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T Binary E "{:b}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T LowerExp E "{:e}");
+        __make_units_internal!(@fmt $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])* T UpperExp E "{:E}");
+        __make_units_internal!(@print_units $to_fmt S $System $(P $print_as;)* $(D $Derived [$($derived_print_as)*])*);
+
+        // --------------------------------------------------------------------------------
+        // Parsing
+
+        impl<V, U> $crate::dimcore::str::FromStr for $System<V, U> where
+            V: $crate::dimcore::str::FromStr,
+            Self: $crate::fmt::PrintUnits,
+        {
+            type Err = $crate::fmt::ParseQuantityError<V::Err>;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::fmt::parse_quantity::<Self, V>(s).map($System::new)
+            }
+        }
 
         // --------------------------------------------------------------------------------
         // Operator traits from this crate
@@ -395,6 +674,98 @@ macro_rules! make_units {
             fn abs(self) -> Self { $System::new(self.value_unsafe.abs()) }
         }
 
+        impl<V: $crate::MaxZero, U> $crate::MaxZero for $System<V,U>
+        {
+            #[inline]
+            fn max_zero(self) -> Self { $System::new(self.value_unsafe.max_zero()) }
+        }
+
+        impl<V: $crate::ClampAbs, U> $crate::ClampAbs for $System<V,U>
+        {
+            #[inline]
+            fn clamp_abs_saturated(self, limit: Self) -> (Self, bool) {
+                let (value, saturated) = self.value_unsafe.clamp_abs_saturated(limit.value_unsafe);
+                ($System::new(value), saturated)
+            }
+        }
+
+        impl<V: $crate::ClampDeadzone, U> $crate::ClampDeadzone for $System<V,U>
+        {
+            #[inline]
+            fn clamp_magnitude_with_deadzone(self, deadzone: Self, max: Self) -> Self {
+                $System::new(self.value_unsafe.clamp_magnitude_with_deadzone(
+                    deadzone.value_unsafe, max.value_unsafe))
+            }
+        }
+
+        impl<V: $crate::RemEuclid, U> $crate::RemEuclid for $System<V,U>
+        {
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $System::new(self.value_unsafe.rem_euclid(rhs.value_unsafe))
+            }
+        }
+
+        impl<V: $crate::fixed::ToFixed, U> $crate::fixed::ToFixed for $System<V,U>
+        {
+            type Output = $System<<V as $crate::fixed::ToFixed>::Output, U>;
+            #[inline]
+            fn to_fixed(self, scale: i32) -> Result<Self::Output, $crate::fixed::FixedOutOfRange> {
+                self.value_unsafe.to_fixed(scale).map($System::new)
+            }
+        }
+
+        impl<V: $crate::fixed::FromFixed, U> $crate::fixed::FromFixed for $System<V,U>
+        {
+            type Output = $System<<V as $crate::fixed::FromFixed>::Output, U>;
+            #[inline]
+            fn from_fixed(self, scale: i32) -> Self::Output {
+                $System::new(self.value_unsafe.from_fixed(scale))
+            }
+        }
+
+        impl<V: $crate::SigFigs, U> $crate::SigFigs for $System<V,U>
+        {
+            #[inline]
+            fn sig_figs(self, digits: u32) -> Self { $System::new(self.value_unsafe.sig_figs(digits)) }
+        }
+
+        impl<V: $crate::Log10Decades, U> $crate::Log10Decades for $System<V,U>
+        {
+            type Output = <V as $crate::Log10Decades>::Output;
+            #[inline]
+            fn log10_decades(self, reference: Self) -> Self::Output {
+                self.value_unsafe.log10_decades(reference.value_unsafe)
+            }
+        }
+
+        impl<V: $crate::Frexp, U> $crate::Frexp for $System<V,U>
+        {
+            #[inline]
+            fn frexp(self) -> (Self, i32) {
+                let (mantissa, exponent) = self.value_unsafe.frexp();
+                ($System::new(mantissa), exponent)
+            }
+        }
+
+        impl<V: $crate::Signum, U> $crate::Signum for $System<V,U>
+        {
+            type Output = $Unitless<<V as $crate::Signum>::Output>;
+            #[inline]
+            fn signum(self) -> Self::Output {
+                $System::new(self.value_unsafe.signum())
+            }
+        }
+
+        impl<V: $crate::SignumStrict, U> $crate::SignumStrict for $System<V,U>
+        {
+            type Output = $Unitless<<V as $crate::SignumStrict>::Output>;
+            #[inline]
+            fn signum_strict(self) -> Self::Output {
+                $System::new(self.value_unsafe.signum_strict())
+            }
+        }
+
         use $crate::typenum::Pow;
         impl<Exp, V, U> Pow<Exp> for $System<V, U>
             where V: Pow<Exp>,
@@ -610,6 +981,17 @@ macro_rules! __make_units_internal {
                     }
                 }
 
+                // Both have units, by reference
+                impl<'a, 'b, Vl, U, Vr> $Trait<&'b $System<Vr, U>> for &'a $System<Vl, U> where
+                    &'a Vl: $Trait<&'b Vr>,
+                {
+                    type Output = $System<<&'a Vl as $Trait<&'b Vr>>::Output, U>;
+                    #[inline]
+                    fn $fun(self, rhs: &'b $System<Vr, U>) -> Self::Output {
+                        $System::new($Trait::$fun(&self.value_unsafe, &rhs.value_unsafe))
+                    }
+                }
+
                 // Unitless on lhs, scalar on rhs
                 #[cfg(feature = "oibit")]
                 impl<Vl, U, Vr> $Trait<Vr> for $System<Vl, U> where
@@ -667,6 +1049,17 @@ macro_rules! __make_units_internal {
                     }
                 }
 
+                // Both have units, by reference
+                impl<'a, 'b, Vl, Ul, Vr, Ur> $Trait<&'b $System<Vr, Ur>> for &'a $System<Vl, Ul> where
+                    &'a Vl: $Trait<&'b Vr>, Ul: $op<Ur>,
+                {
+                    type Output = $System<<&'a Vl as $Trait<&'b Vr>>::Output, <Ul as $op<Ur>>::Output>;
+                    #[inline]
+                    fn $fun(self, rhs: &'b $System<Vr, Ur>) -> Self::Output {
+                        $System::new( $Trait::$fun(&self.value_unsafe, &rhs.value_unsafe) )
+                    }
+                }
+
                 // Lhs has units, scalar on rhs
                 #[cfg(feature = "oibit")]
                 impl<Vl, U, Vr> $Trait<Vr> for $System<Vl, U> where
@@ -709,13 +1102,13 @@ macro_rules! __make_units_internal {
         // -------------------------------------------------------------------------------
         // Rem (it's kinda its own thing)
 
-        // Both have units
-        impl<Vl, Ul, Vr, Ur> Rem<$System<Vr, Ur>> for $System<Vl, Ul> where
+        // Both have units, which must be the same
+        impl<Vl, U, Vr> Rem<$System<Vr, U>> for $System<Vl, U> where
             Vl: Rem<Vr>
         {
-            type Output = $System<<Vl as Rem<Vr>>::Output, Ul>;
+            type Output = $System<<Vl as Rem<Vr>>::Output, U>;
             #[inline]
-            fn rem(self, rhs: $System<Vr, Ur>) -> Self::Output {
+            fn rem(self, rhs: $System<Vr, U>) -> Self::Output {
                 $System::new( self.value_unsafe % rhs.value_unsafe )
             }
         }
@@ -732,12 +1125,12 @@ macro_rules! __make_units_internal {
             }
         }
 
-        // Assign, both have units
-        impl<Vl, Ul, Vr, Ur> RemAssign<$System<Vr, Ur>> for $System<Vl, Ul> where
+        // Assign, both have units, which must be the same
+        impl<Vl, U, Vr> RemAssign<$System<Vr, U>> for $System<Vl, U> where
             Vl: RemAssign<Vr>,
         {
             #[inline]
-            fn rem_assign(&mut self, rhs: $System<Vr, Ur>) {
+            fn rem_assign(&mut self, rhs: $System<Vr, U>) {
                 self.value_unsafe %= rhs.value_unsafe
             }
         }
@@ -947,7 +1340,8 @@ macro_rules! __make_units_internal {
         prim!(char);
     );
 
-    (@fmt true S $System:ident $(P $print_as:expr;)* T $Trait:ident E $token:expr) => (
+    (@fmt true S $System:ident $(P $print_as:expr;)* $(D $Derived:ident [$($derived_print_as:tt)*])*
+     T $Trait:ident E $token:expr) => (
         impl<V, U> fmt::$Trait for $System<V, U> where
             V: fmt::$Trait,
         Length<U>: ArrayLength<isize>,
@@ -960,7 +1354,15 @@ macro_rules! __make_units_internal {
 
                 let mut first = true;
 
-                self.value_unsafe.fmt(f)?;
+                __make_units_internal!(@fmt_value $Trait f self.value_unsafe)?;
+
+                // Derived units with their own tokens print as those, if they match exactly; the
+                // first one listed wins
+                $($(
+                    if exponents[..] == <inner::$Derived as ToGA>::to_ga()[..] {
+                        return write!(f, " {}", $derived_print_as);
+                    }
+                )*)*
 
                 for (exp, token) in
                     exponents.into_iter()
@@ -978,6 +1380,10 @@ macro_rules! __make_units_internal {
                     match exp {
                         0 => (),
                         1 => write!(f, "{}", token)?,
+                        _ if f.alternate() => {
+                            write!(f, "{}", token)?;
+                            $crate::fmt::write_superscript(f, exp)?
+                        },
                         _ => {
                             write!(f, "{}^{}", token, exp)?
                         },
@@ -988,7 +1394,49 @@ macro_rules! __make_units_internal {
         }
     );
 
-    (@fmt false S $System:ident $(P $print_as:expr;)* T $Trait:ident E $token:expr) => ();
+    // Display prints negative zero as zero; the other traits print values as they are
+    (@fmt_value Display $f:ident $value:expr) => ($crate::fmt::display_value($f, &$value));
+    (@fmt_value $Trait:ident $f:ident $value:expr) => (fmt::$Trait::fmt(&$value, $f));
+
+    (@fmt false S $System:ident $(P $print_as:expr;)* $(D $Derived:ident [$($derived_print_as:tt)*])*
+     T $Trait:ident E $token:expr) => ();
+
+    (@print_units true S $System:ident $(P $print_as:expr;)* $(D $Derived:ident [$($derived_print_as:tt)*])*) => (
+        impl<V, U> $crate::fmt::PrintUnits for $System<V, U> where
+            Length<U>: ArrayLength<isize>,
+            U: TypeArray + Len + ToGA<Output = GenericArray<isize, Length<U>>>,
+        {
+            fn for_each_unit<F>(mut f: F) -> Result<(), fmt::Error>
+                where F: FnMut(&'static str, isize, isize) -> Result<(), fmt::Error>,
+            {
+                let exponents = U::to_ga();
+
+                $($(
+                    if exponents[..] == <inner::$Derived as ToGA>::to_ga()[..] {
+                        return f($derived_print_as, 1, 1);
+                    }
+                )*)*
+
+                Self::for_each_base_unit(f)
+            }
+
+            fn for_each_base_unit<F>(mut f: F) -> Result<(), fmt::Error>
+                where F: FnMut(&'static str, isize, isize) -> Result<(), fmt::Error>,
+            {
+                let exponents = U::to_ga();
+                let print_tokens = [$($print_as),*];
+
+                for (exp, &token) in exponents.into_iter().zip(print_tokens.iter()) {
+                    if exp != 0 {
+                        f(token, exp, 1)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    );
+
+    (@print_units false S $System:ident $(P $print_as:expr;)* $(D $Derived:ident [$($derived_print_as:tt)*])*) => ();
 
     // define arrays for all the base units
     (@base_arrays $Unitless:ident $Unit:ident $($Units:ident)*) => (
@@ -1078,6 +1526,94 @@ macro_rules! derived {
     );
 }
 
+/// Assert that a unit expression reduces to an expected unit
+///
+/// This macro is mainly useful for testing. It accepts the same expressions as `derived!`, and
+/// compiles only if the expression on the right-hand side has exactly the same units as the unit on
+/// the left-hand side. It must be used where a statement is expected, such as inside a function.
+///
+/// # Example
+/// ```rust
+/// #[macro_use]
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+///
+/// fn main() {
+///     dimension_reduces_to!(si: Meter = Newton * Meter / Newton);
+///     dimension_reduces_to!(si: Joule = Kilogram * Meter2 / Second2);
+/// }
+/// ```
+///
+/// If the reduction does not hold, it will fail to compile:
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+///
+/// fn main() {
+///     dimension_reduces_to!(si: Meter = Newton * Meter);
+/// }
+/// ```
+#[macro_export]
+macro_rules! dimension_reduces_to {
+    ($module:ident: $expected:ident = $($tail:tt)*) => (
+        let _: fn(__derived_internal!(@commas $module, $($tail)*)) -> $module::inner::$expected =
+            |units| units;
+    );
+}
+
+/// Assert that a unit expression has a given dimension
+///
+/// This macro checks a physics formula at compile time. The left-hand side is one of the marker
+/// traits from the `dimensions` module, and the right-hand side is an expression of units as in
+/// `derived!`, which requires the base type of your unit system and the module it was defined in
+/// to be given. It compiles only if the quantity type that the expression reduces to implements the
+/// marker trait. It must be used where a statement is expected, such as inside a function.
+///
+/// # Example
+/// ```rust
+/// #[macro_use]
+/// extern crate dimensioned as dim;
+///
+/// use dim::si::{self, SI};
+///
+/// fn main() {
+///     // F = m a
+///     dimensional_equation!(si, SI: Force = Kilogram * MeterPerSecond2);
+///     // E = F d
+///     dimensional_equation!(si, SI: Energy = Newton * Meter);
+///     // P = V I
+///     dimensional_equation!(si, SI: Power = Volt * Ampere);
+///     // v = d / t
+///     dimensional_equation!(si, SI: Velocity = Meter / Second);
+/// }
+/// ```
+///
+/// If the equation is wrong, it will fail to compile:
+///
+/// ```rust,compile_fail
+/// #[macro_use]
+/// extern crate dimensioned as dim;
+///
+/// use dim::si::{self, SI};
+///
+/// fn main() {
+///     dimensional_equation!(si, SI: Force = Kilogram * MeterPerSecond);
+/// }
+/// ```
+#[macro_export]
+macro_rules! dimensional_equation {
+    ($module:ident, $System:ident: $Dimension:ident = $($tail:tt)*) => (
+        {
+            fn has_dimension<Q: $crate::dimensions::$Dimension>() {}
+            has_dimension::<$System<f64, __derived_internal!(@commas $module, $($tail)*)>>();
+        }
+    );
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __derived_internal {
@@ -1136,7 +1672,8 @@ macro_rules! __derived_internal {
 /// values with this type.  Specifically, this allows you to use the
 /// `Standard` and `Uniform` distributions to generate dimensioned
 /// types, provided your underlying data type supports those
-/// distributions.
+/// distributions. This includes `Rng::gen_range(low, high)`, where
+/// both bounds must have the same units, as must those of `Uniform`.
 ///
 /// ```rust,ignore
 /// impl_rand!(UnitSystem);
@@ -1174,7 +1711,10 @@ macro_rules! impl_rand {
                 }
             }
             fn new_inclusive(low: Self::X, high: Self::X) -> Self {
-                UniformSampler::new(low, high)
+                MyUniformSampler {
+                    inner: V::Sampler::new_inclusive(low.value_unsafe, high.value_unsafe),
+                    _marker: marker::PhantomData,
+                }
             }
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
                 $System::new(self.inner.sample(rng))
@@ -1186,6 +1726,78 @@ macro_rules! impl_rand {
     };
 }
 
+/// Implement quickcheck traits for a unit system.
+///
+/// This implements `Arbitrary` for quantities whose value type implements it, so that they may be
+/// used directly as arguments to quickcheck properties. Arbitrary quantities are generated and
+/// shrunk exactly as their values are.
+///
+/// ```rust,ignore
+/// impl_quickcheck!(UnitSystem);
+/// ```
+#[cfg(feature = "quickcheck")]
+#[macro_export]
+macro_rules! impl_quickcheck {
+    ($System:ident) => {
+        use $crate::quickcheck::{Arbitrary, Gen};
+
+        impl<V, U> Arbitrary for $System<V, U>
+        where
+            V: Arbitrary,
+            U: Clone + Send + 'static,
+        {
+            fn arbitrary<G: Gen>(g: &mut G) -> Self {
+                $System::new(V::arbitrary(g))
+            }
+
+            fn shrink(&self) -> Box<Iterator<Item = Self>> {
+                Box::new(self.value_unsafe.shrink().map($System::new))
+            }
+        }
+    };
+}
+
+/// Implement `TryFrom` between quantities with different primitive integer value types.
+///
+/// For any two of `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`, `u32`, `u64`, and `usize`,
+/// this implements `TryFrom` for quantities with the same units, converting the value with its
+/// own `TryFrom`. Narrowing conversions therefore fail when the value is out of range.
+///
+/// As `TryFrom` was stabilized in Rust 1.34.0, this is behind the `try_from` feature.
+///
+/// ```rust,ignore
+/// impl_try_from!(UnitSystem);
+/// ```
+#[cfg(feature = "try_from")]
+#[macro_export]
+macro_rules! impl_try_from {
+    ($System:ident) => {
+        impl_try_from!(@impl $System; i8 => i16 i32 i64 isize u8 u16 u32 u64 usize);
+        impl_try_from!(@impl $System; i16 => i8 i32 i64 isize u8 u16 u32 u64 usize);
+        impl_try_from!(@impl $System; i32 => i8 i16 i64 isize u8 u16 u32 u64 usize);
+        impl_try_from!(@impl $System; i64 => i8 i16 i32 isize u8 u16 u32 u64 usize);
+        impl_try_from!(@impl $System; isize => i8 i16 i32 i64 u8 u16 u32 u64 usize);
+        impl_try_from!(@impl $System; u8 => i8 i16 i32 i64 isize u16 u32 u64 usize);
+        impl_try_from!(@impl $System; u16 => i8 i16 i32 i64 isize u8 u32 u64 usize);
+        impl_try_from!(@impl $System; u32 => i8 i16 i32 i64 isize u8 u16 u64 usize);
+        impl_try_from!(@impl $System; u64 => i8 i16 i32 i64 isize u8 u16 u32 usize);
+        impl_try_from!(@impl $System; usize => i8 i16 i32 i64 isize u8 u16 u32 u64);
+    };
+    (@impl $System:ident; $from:ident => $($to:ident)*) => {
+        $(
+            impl<U> $crate::dimcore::convert::TryFrom<$System<$from, U>> for $System<$to, U> {
+                type Error = <$to as $crate::dimcore::convert::TryFrom<$from>>::Error;
+
+                #[inline]
+                fn try_from(other: $System<$from, U>) -> Result<Self, Self::Error> {
+                    <$to as $crate::dimcore::convert::TryFrom<$from>>::try_from(other.value_unsafe)
+                        .map($System::new)
+                }
+            }
+        )*
+    };
+}
+
 /// Implement serde traits for a unit system.
 ///
 /// The implementations generated by this macro only serialize the
@@ -1193,6 +1805,10 @@ macro_rules! impl_rand {
 /// dimensionally unsafe, but it does not add any overhead over using
 /// plain numeric types.
 ///
+/// A quantity is serialized exactly as its value is, with no wrapping
+/// newtype, as with `#[serde(transparent)]`. Binary formats therefore
+/// produce the same bytes for a quantity as for its raw value.
+///
 /// All of the unit systems defined in this crate implement
 /// `Serialize` and `Deserialize` using this macro. If you define your
 /// own system you may use this macro to implement those traits
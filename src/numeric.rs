@@ -0,0 +1,694 @@
+//! Numerical methods for quantities
+//!
+//! The functions and traits in this module are generic with regards to unit system, and are
+//! written so that they are dimensionally safe; that is, they only typecheck when the units of
+//! their inputs are consistent.
+
+use core::marker::PhantomData;
+use core::ops::{Add, Mul};
+use dimensions::Time;
+use typenum::{Prod, Sum};
+use {Dimensioned, Dimensionless};
+
+/// Evaluate a polynomial using Horner's method, where the coefficients may have different units.
+///
+/// Each power of `x` changes the units, so the coefficient of `x^n` must have the units of the
+/// result divided by those of `x^n`. By implementing this trait for tuples of coefficients, the
+/// compiler checks that this is true. The coefficients are given in increasing order of power, so
+/// `(c0, c1, c2).horner(x)` is `c0 + c1*x + c2*x^2`.
+///
+/// It is implemented for tuples of up to eight coefficients.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::Horner;
+/// use dim::si;
+///
+/// fn main() {
+///     // The position of a particle with initial position `x0`, velocity `v0`, and constant
+///     // acceleration `a`.
+///     let x0 = 1.0 * si::M;
+///     let v0 = 2.0 * si::MPS;
+///     let a = 4.0 * si::MPS2;
+///
+///     let t = 3.0 * si::S;
+///     let x = (x0, v0, 0.5 * a).horner(t);
+///
+///     assert_eq!(x, 25.0 * si::M);
+/// }
+/// ```
+///
+/// Coefficients with inconsistent units will not compile:
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::Horner;
+/// use dim::si;
+///
+/// fn main() {
+///     let x = (1.0 * si::M, 2.0 * si::M).horner(3.0 * si::S);
+/// }
+/// ```
+pub trait Horner<X> {
+    /// The type of the polynomial's value
+    type Output;
+
+    /// Evaluate the polynomial at `x`
+    fn horner(self, x: X) -> Self::Output;
+}
+
+impl<X, C0> Horner<X> for (C0,) {
+    type Output = C0;
+    #[inline]
+    fn horner(self, _: X) -> Self::Output {
+        self.0
+    }
+}
+
+macro_rules! impl_horner {
+    ($C0:ident $c0:ident) => {};
+    ($C0:ident $c0:ident, $($C:ident $c:ident),+) => {
+        impl<X, $C0, $($C),+> Horner<X> for ($C0, $($C),+)
+        where
+            X: Copy + Mul<<($($C,)+) as Horner<X>>::Output>,
+            ($($C,)+): Horner<X>,
+            $C0: Add<Prod<X, <($($C,)+) as Horner<X>>::Output>>,
+        {
+            type Output = Sum<$C0, Prod<X, <($($C,)+) as Horner<X>>::Output>>;
+            #[inline]
+            fn horner(self, x: X) -> Self::Output {
+                let ($c0, $($c),+) = self;
+                $c0 + x * ($($c,)+).horner(x)
+            }
+        }
+
+        impl_horner!($($C $c),+);
+    };
+}
+
+impl_horner!(C0 c0, C1 c1, C2 c2, C3 c3, C4 c4, C5 c5, C6 c6, C7 c7);
+
+/// The dot product of a tuple of coefficients with a tuple of quantities, where each may have
+/// different units.
+///
+/// Each product `c_i * x_i` must have the same type, so that they may be added. This is the
+/// building block of `Jacobian`, where it is one row of the matrix. It is implemented for tuples
+/// of up to six elements.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::TupleDot;
+/// use dim::si;
+///
+/// fn main() {
+///     // The cost of electricity and of water
+///     let prices = (0.25 / si::J, 2.0 / si::M3);
+///     let usage = (8.0 * si::J, 0.5 * si::M3);
+///
+///     assert_eq!(prices.dot(usage), 3.0 * si::ONE);
+/// }
+/// ```
+pub trait TupleDot<X> {
+    /// The type of the sum of the products
+    type Output;
+
+    /// Compute the dot product with `x`
+    fn dot(self, x: X) -> Self::Output;
+}
+
+/// Propagate perturbations through a linearized relation, given by its Jacobian matrix.
+///
+/// For a relation `y = f(x)` with several inputs and outputs, each of which may have different
+/// units, a small change `dx` in the inputs gives a change `dy_i = sum_j J_ij * dx_j` in each
+/// output, where `J_ij` is the partial derivative of `y_i` with regards to `x_j`, and so has the
+/// units of `y_i / x_j`.
+///
+/// As the units vary from element to element, the matrix is given as a tuple of rows, each of which
+/// is a tuple of partial derivatives, and the perturbations as a tuple of quantities. The units of
+/// each row must be consistent, so that every term of `dy_i` has the same units; otherwise, it will
+/// not compile. The result is a tuple of the changes in each output.
+///
+/// Matrices of up to six rows are supported, each of up to six elements.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::Jacobian;
+/// use dim::si;
+///
+/// fn main() {
+///     // The current and power dissipated by a resistor, as functions of voltage and resistance,
+///     // linearized about V = 10 V and R = 5 ohm.
+///     let (v, r) = (10.0 * si::V, 5.0 * si::OHM);
+///     let jacobian = (
+///         (1.0 / r, -v / (r * r)),
+///         (2.0 * v / r, -v * v / (r * r)),
+///     );
+///
+///     let (di, dp) = jacobian.propagate((0.5 * si::V, 0.1 * si::OHM));
+///
+///     assert_eq!(di, 0.06 * si::A);
+///     assert_eq!(dp, 1.6 * si::W);
+/// }
+/// ```
+pub trait Jacobian<X> {
+    /// The type of the tuple of changes in the outputs
+    type Output;
+
+    /// Propagate the perturbations `dx`
+    fn propagate(self, dx: X) -> Self::Output;
+}
+
+macro_rules! impl_tuple_dot {
+    ($(($C:ident $c:ident $X:ident $x:ident))+) => {
+        impl<O, $($C, $X),+> TupleDot<($($X,)+)> for ($($C,)+)
+        where
+            O: Add<Output = O>,
+            $($C: Mul<$X, Output = O>),+
+        {
+            type Output = O;
+            #[inline]
+            fn dot(self, x: ($($X,)+)) -> Self::Output {
+                let ($($c,)+) = self;
+                let ($($x,)+) = x;
+                impl_tuple_dot!(@sum $($c * $x),+)
+            }
+        }
+    };
+    (@sum $first:expr $(, $rest:expr)*) => {
+        $first $(+ $rest)*
+    };
+}
+
+impl_tuple_dot!((C0 c0 X0 x0));
+impl_tuple_dot!((C0 c0 X0 x0) (C1 c1 X1 x1));
+impl_tuple_dot!((C0 c0 X0 x0) (C1 c1 X1 x1) (C2 c2 X2 x2));
+impl_tuple_dot!((C0 c0 X0 x0) (C1 c1 X1 x1) (C2 c2 X2 x2) (C3 c3 X3 x3));
+impl_tuple_dot!((C0 c0 X0 x0) (C1 c1 X1 x1) (C2 c2 X2 x2) (C3 c3 X3 x3) (C4 c4 X4 x4));
+impl_tuple_dot!((C0 c0 X0 x0) (C1 c1 X1 x1) (C2 c2 X2 x2) (C3 c3 X3 x3) (C4 c4 X4 x4) (C5 c5 X5 x5));
+
+macro_rules! impl_jacobian {
+    ($($R:ident $r:ident),+) => {
+        impl<X: Copy, $($R),+> Jacobian<X> for ($($R,)+)
+        where
+            $($R: TupleDot<X>),+
+        {
+            type Output = ($(<$R as TupleDot<X>>::Output,)+);
+            #[inline]
+            fn propagate(self, dx: X) -> Self::Output {
+                let ($($r,)+) = self;
+                ($($r.dot(dx),)+)
+            }
+        }
+    };
+}
+
+impl_jacobian!(R0 r0);
+impl_jacobian!(R0 r0, R1 r1);
+impl_jacobian!(R0 r0, R1 r1, R2 r2);
+impl_jacobian!(R0 r0, R1 r1, R2 r2, R3 r3);
+impl_jacobian!(R0 r0, R1 r1, R2 r2, R3 r3, R4 r4);
+impl_jacobian!(R0 r0, R1 r1, R2 r2, R3 r3, R4 r4, R5 r5);
+
+/// Evaluate a polynomial with dimensionless coefficients at a dimensionless `x`.
+///
+/// The coefficients are given in increasing order of power, so `&[c0, c1, c2]` evaluates to
+/// `c0 + c1*x + c2*x^2`. An empty slice of coefficients evaluates to zero. With the `std` feature,
+/// each step uses a fused multiply-add.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::horner;
+/// use dim::si;
+///
+/// fn main() {
+///     let x = 2.0 * si::ONE;
+///     let coefficients = [1.0 * si::ONE, 2.0 * si::ONE, 3.0 * si::ONE];
+///
+///     assert_eq!(horner(x, &coefficients), 17.0 * si::ONE);
+/// }
+/// ```
+pub fn horner<Q>(x: Q, coefficients: &[Q]) -> Q
+where
+    Q: Dimensionless<Value = f64>,
+{
+    let x = *x.value();
+    let value = coefficients.iter().rev().fold(0.0, |acc: f64, c| {
+        #[cfg(feature = "std")]
+        return acc.mul_add(x, *c.value());
+        #[cfg(not(feature = "std"))]
+        return acc * x + *c.value();
+    });
+    Q::new(value)
+}
+
+/// Compute the dot product of two slices of quantities; the sum of the products of their
+/// elements.
+///
+/// The elements of each slice must share units, and the result has the units of their product.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::dot_slices;
+/// use dim::si;
+///
+/// fn main() {
+///     // The work done by a force over a path of segments
+///     let forces = [2.0 * si::N, 3.0 * si::N, 1.0 * si::N];
+///     let displacements = [1.0 * si::M, 2.0 * si::M, 4.0 * si::M];
+///
+///     assert_eq!(dot_slices(&forces, &displacements), 12.0 * si::J);
+/// }
+/// ```
+pub fn dot_slices<A, B>(a: &[A], b: &[B]) -> Prod<A, B>
+where
+    A: Dimensioned<Value = f64> + Mul<B>,
+    B: Dimensioned<Value = f64>,
+    Prod<A, B>: Dimensioned<Value = f64>,
+{
+    assert_eq!(a.len(), b.len(), "slices must have equal lengths");
+    let value = a
+        .iter()
+        .zip(b)
+        .fold(0.0, |acc, (a, b)| acc + a.value_unsafe() * b.value_unsafe());
+    Prod::<A, B>::new(value)
+}
+
+/// Integrate equally spaced samples with the trapezoidal rule.
+///
+/// The samples are taken `dx` apart, and the integral has the units of their product. With fewer
+/// than two samples, there is no interval to integrate over, and the integral is zero.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::trapezoid;
+/// use dim::si;
+///
+/// fn main() {
+///     // The distance travelled from speeds sampled every two seconds
+///     let speeds = [0.0 * si::MPS, 2.0 * si::MPS, 4.0 * si::MPS];
+///
+///     assert_eq!(trapezoid(&speeds, 2.0 * si::S), 8.0 * si::M);
+/// }
+/// ```
+pub fn trapezoid<Y, X>(values: &[Y], dx: X) -> Prod<Y, X>
+where
+    Y: Dimensioned<Value = f64> + Mul<X>,
+    X: Dimensioned<Value = f64>,
+    Prod<Y, X>: Dimensioned<Value = f64>,
+{
+    let sum = values.windows(2).fold(0.0, |acc, pair| {
+        acc + pair[0].value_unsafe() + pair[1].value_unsafe()
+    });
+    Prod::<Y, X>::new(0.5 * sum * dx.value_unsafe())
+}
+
+/// Restrict a quantity to the interval `[lo, hi]`.
+///
+/// This works for any quantity, so bounding logic can be written once for any units. The bounds
+/// must have the same units as `x`. If `x` is `NaN`, it is returned unchanged.
+///
+/// # Panics
+///
+/// Panics if `lo > hi`, or if either bound is `NaN`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::clamp;
+/// use dim::si;
+///
+/// fn main() {
+///     let lo = 0.0 * si::M;
+///     let hi = 10.0 * si::M;
+///
+///     assert_eq!(clamp(12.0 * si::M, lo, hi), 10.0 * si::M);
+///     assert_eq!(clamp(-1.0 * si::M, lo, hi), 0.0 * si::M);
+///     assert_eq!(clamp(4.0 * si::M, lo, hi), 4.0 * si::M);
+/// }
+/// ```
+pub fn clamp<Q>(x: Q, lo: Q, hi: Q) -> Q
+where
+    Q: Dimensioned + PartialOrd,
+{
+    assert!(lo <= hi, "clamp requires lo <= hi");
+    if x < lo {
+        lo
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+
+/// Sample a linearly interpolated animation curve, given as a slice of keyframes, at time `t`.
+///
+/// Each keyframe is a pair of a time and a value, and the frames must be sorted by time. The
+/// values may have any units, but they must all be the same. Between two keyframes, the value is
+/// interpolated linearly, and at a keyframe, its value is given exactly.
+///
+/// Returns `None` if `t` is before the first keyframe or after the last, or if there are no
+/// keyframes.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::sample_keyframes;
+/// use dim::si;
+///
+/// fn main() {
+///     let frames = [
+///         (0.0 * si::S, 0.0 * si::M),
+///         (2.0 * si::S, 4.0 * si::M),
+///         (3.0 * si::S, 1.0 * si::M),
+///     ];
+///
+///     assert_eq!(sample_keyframes(&frames, 1.0 * si::S), Some(2.0 * si::M));
+///     assert_eq!(sample_keyframes(&frames, 2.5 * si::S), Some(2.5 * si::M));
+///     assert_eq!(sample_keyframes(&frames, 4.0 * si::S), None);
+/// }
+/// ```
+pub fn sample_keyframes<T, Q>(frames: &[(T, Q)], t: T) -> Option<Q>
+where
+    T: Time + Dimensioned<Value = f64>,
+    Q: Dimensioned<Value = f64>,
+{
+    let t = *t.value_unsafe();
+    if let Some(&(ref t0, ref v0)) = frames.first() {
+        if *t0.value_unsafe() == t {
+            return Some(Q::new(*v0.value_unsafe()));
+        }
+    }
+    frames
+        .windows(2)
+        .filter_map(|pair| {
+            let (t0, v0) = (*pair[0].0.value_unsafe(), *pair[0].1.value_unsafe());
+            let (t1, v1) = (*pair[1].0.value_unsafe(), *pair[1].1.value_unsafe());
+            if t == t1 {
+                Some(Q::new(v1))
+            } else if t0 < t && t < t1 {
+                let fraction = (t - t0) / (t1 - t0);
+                Some(Q::new(v0 + (v1 - v0) * fraction))
+            } else {
+                None
+            }
+        })
+        .next()
+}
+
+/// An accumulator for summing quantities with Kahan compensated summation.
+///
+/// Adding many quantities one after another loses precision as the running total grows, as the
+/// low bits of each addend are rounded away. This keeps track of the error lost with each
+/// addition, so the total is accurate to within a few rounding errors, however many quantities
+/// are added.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::KahanSum;
+/// use dim::si;
+///
+/// fn main() {
+///     let mut sum = KahanSum::new();
+///     sum.add(1.0 * si::M);
+///     for _ in 0..10 {
+///         sum.add(1e-16 * si::M);
+///     }
+///
+///     assert_eq!(sum.total(), 1.000_000_000_000_001 * si::M);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct KahanSum<Q> {
+    sum: f64,
+    compensation: f64,
+    _marker: PhantomData<Q>,
+}
+
+impl<Q> KahanSum<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    /// Create an accumulator with a total of zero.
+    pub fn new() -> Self {
+        KahanSum {
+            sum: 0.0,
+            compensation: 0.0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Add a quantity to the total.
+    pub fn add(&mut self, quantity: Q) {
+        let y = *quantity.value_unsafe() - self.compensation;
+        let t = self.sum + y;
+        self.compensation = (t - self.sum) - y;
+        self.sum = t;
+    }
+
+    /// Get the total of the quantities added so far.
+    pub fn total(&self) -> Q {
+        Q::new(self.sum)
+    }
+}
+
+impl<Q> Default for KahanSum<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    fn default() -> Self {
+        KahanSum::new()
+    }
+}
+
+/// Multiply every quantity in a slice by a dimensionless `factor`, giving a new vector of
+/// quantities with the same units.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::scale_all;
+/// use dim::si;
+///
+/// fn main() {
+///     let lengths = [1.0 * si::M, 2.5 * si::M];
+///     assert_eq!(scale_all(&lengths, 2.0), vec![2.0 * si::M, 5.0 * si::M]);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn scale_all<Q>(quantities: &[Q], factor: f64) -> Vec<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    quantities
+        .iter()
+        .map(|q| Q::new(q.value_unsafe() * factor))
+        .collect()
+}
+
+/// Add `delta` to every quantity in a slice, giving a new vector of quantities with the same
+/// units. The offset must have the same units as the quantities.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::offset_all;
+/// use dim::si;
+///
+/// fn main() {
+///     let times = [1.0 * si::S, 2.5 * si::S];
+///     assert_eq!(offset_all(&times, 0.5 * si::S), vec![1.5 * si::S, 3.0 * si::S]);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn offset_all<Q>(quantities: &[Q], delta: Q) -> Vec<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    quantities
+        .iter()
+        .map(|q| Q::new(q.value_unsafe() + delta.value_unsafe()))
+        .collect()
+}
+
+/// Compute the running totals of a slice of quantities, so that each element of the result is the
+/// sum of the quantities up to and including that point.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::cumsum;
+/// use dim::si;
+///
+/// fn main() {
+///     // The displacements over each step give the positions after each step
+///     let steps = [1.0 * si::M, 2.0 * si::M, -0.5 * si::M];
+///     assert_eq!(cumsum(&steps), vec![1.0 * si::M, 3.0 * si::M, 2.5 * si::M]);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn cumsum<Q>(quantities: &[Q]) -> Vec<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    quantities
+        .iter()
+        .scan(0.0, |total, q| {
+            *total += *q.value_unsafe();
+            Some(Q::new(*total))
+        })
+        .collect()
+}
+
+/// Compute the running integral of a slice of rates, each held for a step of `dt`.
+///
+/// This is `cumsum` of each rate multiplied by `dt`, so the first element of the result is
+/// `rates[0] * dt`, and the last is the integral over all of the steps. The results have the units
+/// of a rate times `dt`; for example, integrating velocities over time gives positions.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::cumulative_integrate;
+/// use dim::si;
+///
+/// fn main() {
+///     let velocities = [1.0 * si::MPS, 3.0 * si::MPS, -2.0 * si::MPS];
+///     assert_eq!(
+///         cumulative_integrate(&velocities, 0.5 * si::S),
+///         vec![0.5 * si::M, 2.0 * si::M, 1.0 * si::M]
+///     );
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn cumulative_integrate<R, T>(rates: &[R], dt: T) -> Vec<Prod<R, T>>
+where
+    R: Dimensioned<Value = f64> + Mul<T>,
+    T: Dimensioned<Value = f64>,
+    Prod<R, T>: Dimensioned<Value = f64>,
+{
+    let dt = *dt.value_unsafe();
+    rates
+        .iter()
+        .scan(0.0, |total, rate| {
+            *total += rate.value_unsafe() * dt;
+            Some(Prod::<R, T>::new(*total))
+        })
+        .collect()
+}
+
+/// Resample a signal from one sample period to another, interpolating linearly.
+///
+/// The input is sampled every `from_dt`, starting at time zero, and the output every `to_dt`,
+/// over the same span of time; a smaller `to_dt` upsamples and a larger one downsamples. The output
+/// starts with the first input sample, and ends with the last output time that is within the input,
+/// so both endpoints are kept whenever the span is a whole number of output periods. The samples
+/// may have any units, and the periods must have units of time.
+///
+/// # Panics
+///
+/// Panics if either period is not positive.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::resample;
+/// use dim::si;
+///
+/// fn main() {
+///     let samples = [0.0 * si::V, 2.0 * si::V, 1.0 * si::V];
+///     assert_eq!(
+///         resample(&samples, 1.0 * si::S, 0.5 * si::S),
+///         vec![0.0 * si::V, 1.0 * si::V, 2.0 * si::V, 1.5 * si::V, 1.0 * si::V]
+///     );
+///     assert_eq!(
+///         resample(&samples, 1.0 * si::S, 2.0 * si::S),
+///         vec![0.0 * si::V, 1.0 * si::V]
+///     );
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn resample<Q, T>(input: &[Q], from_dt: T, to_dt: T) -> Vec<Q>
+where
+    Q: Dimensioned<Value = f64>,
+    T: Time + Dimensioned<Value = f64>,
+{
+    let (from_dt, to_dt) = (*from_dt.value_unsafe(), *to_dt.value_unsafe());
+    assert!(
+        from_dt > 0.0 && to_dt > 0.0,
+        "resample requires positive sample periods"
+    );
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    let last = input.len() - 1;
+    // Allow for rounding, so that an end that falls on an output sample is kept
+    let outputs = (last as f64 * from_dt / to_dt + 1e-9).floor() as usize + 1;
+    (0..outputs)
+        .map(|k| {
+            let position = k as f64 * to_dt / from_dt;
+            let i = position as usize;
+            if i >= last {
+                return Q::new(*input[last].value_unsafe());
+            }
+            let (v0, v1) = (*input[i].value_unsafe(), *input[i + 1].value_unsafe());
+            Q::new(v0 + (v1 - v0) * (position - i as f64))
+        })
+        .collect()
+}
+
+/// Blend some quantities by weight, as `Σ(value * weight)`.
+///
+/// This generalizes linear interpolation to any number of targets: blending `a` and `b` with
+/// weights `1 - t` and `t` is the same as interpolating from `a` to `b` by `t`. The weights are
+/// dimensionless and should sum to one, but are not normalized, so that weights that don't sum to
+/// one scale the result; use `stats::weighted_mean` to normalize them. No targets blend to zero.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::numeric::blend;
+/// use dim::si;
+///
+/// fn main() {
+///     let targets = [(2.0 * si::M, 0.5), (4.0 * si::M, 0.25), (8.0 * si::M, 0.25)];
+///     assert_eq!(blend(&targets), 4.0 * si::M);
+/// }
+/// ```
+pub fn blend<Q>(targets: &[(Q, f64)]) -> Q
+where
+    Q: Dimensioned<Value = f64>,
+{
+    Q::new(
+        targets
+            .iter()
+            .fold(0.0, |sum, &(ref q, weight)| sum + q.value_unsafe() * weight),
+    )
+}
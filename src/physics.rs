@@ -0,0 +1,349 @@
+//! Formulas from physics
+//!
+//! The functions in this module are generic with regards to unit system, using the marker traits
+//! in the `dimensions` module, and give results with the units that the formulas imply.
+//!
+//! Angles are taken to be dimensionless, as they are in `SI`, so an angular velocity has units of
+//! reciprocal time. Systems such as `UCUM` that have a unit for angle therefore do not work with
+//! the rotational formulas here.
+
+use core::ops::{Div, Mul};
+use dimensions::{
+    Acceleration, AngularAcceleration, Conductance, Energy, Force, Length, Mass, MomentOfInertia,
+    Resistance, Time, Velocity, Volume,
+};
+use typenum::{Prod, Quot};
+use Dimensioned;
+use Recip;
+
+/// Compute the linear velocity of a point moving in a circle of radius `radius` with angular
+/// velocity `angular_velocity`, as `angular_velocity * radius`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::linear_velocity;
+/// use dim::si;
+///
+/// fn main() {
+///     let w = 3.0 * si::RAD / si::S;
+///     let r = 2.0 * si::M;
+///
+///     assert_eq!(linear_velocity(w, r), 6.0 * si::MPS);
+/// }
+/// ```
+pub fn linear_velocity<W, R>(angular_velocity: W, radius: R) -> Prod<W, R>
+where
+    W: Mul<R>,
+    R: Length,
+    Prod<W, R>: Velocity,
+{
+    angular_velocity * radius
+}
+
+/// Compute the angular velocity of a point moving in a circle of radius `radius` with linear
+/// velocity `velocity`, as `velocity / radius`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::angular_velocity;
+/// use dim::si;
+///
+/// fn main() {
+///     let v = 6.0 * si::MPS;
+///     let r = 2.0 * si::M;
+///
+///     assert_eq!(angular_velocity(v, r), 3.0 * si::RAD / si::S);
+/// }
+/// ```
+pub fn angular_velocity<V, R>(velocity: V, radius: R) -> Quot<V, R>
+where
+    V: Velocity + Div<R>,
+    R: Length,
+{
+    velocity / radius
+}
+
+/// Compute the centripetal acceleration of a point moving in a circle of radius `radius` with
+/// linear velocity `velocity`, as `velocity^2 / radius`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::centripetal_acceleration;
+/// use dim::si;
+///
+/// fn main() {
+///     let v = 6.0 * si::MPS;
+///     let r = 2.0 * si::M;
+///
+///     assert_eq!(centripetal_acceleration(v, r), 18.0 * si::MPS2);
+/// }
+/// ```
+pub fn centripetal_acceleration<V, R>(velocity: V, radius: R) -> Quot<Prod<V, V>, R>
+where
+    V: Velocity + Mul + Copy,
+    R: Length,
+    Prod<V, V>: Div<R>,
+    Quot<Prod<V, V>, R>: Acceleration,
+{
+    velocity * velocity / radius
+}
+
+/// Compute the torque exerted by a force `force` acting perpendicular to a lever arm of length
+/// `lever`, as `force * lever`.
+///
+/// Torque has the same dimensions as energy, and this crate does not distinguish between them, so
+/// in `SI` the result is a `Joule`, although it is more usually written as a newton-meter. Take
+/// care not to add a torque to an energy.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::torque;
+/// use dim::si;
+///
+/// fn main() {
+///     let f = 20.0 * si::N;
+///     let r = 0.25 * si::M;
+///
+///     assert_eq!(torque(f, r), 5.0 * si::N * si::M);
+/// }
+/// ```
+pub fn torque<F, L>(force: F, lever: L) -> Prod<F, L>
+where
+    F: Force + Mul<L>,
+    L: Length,
+    Prod<F, L>: Energy,
+{
+    force * lever
+}
+
+/// Compute the moment of inertia of a point mass `mass` at a distance `radius` from the axis of
+/// rotation, as `mass * radius^2`.
+///
+/// The moments of inertia of extended bodies are sums of these, or can be built from the usual
+/// formulas, such as `0.5 * mass * radius * radius` for a solid cylinder.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::moment_of_inertia;
+/// use dim::si;
+///
+/// fn main() {
+///     let m = 2.0 * si::KG;
+///     let r = 3.0 * si::M;
+///
+///     assert_eq!(moment_of_inertia(m, r), 18.0 * si::KG * si::M2);
+/// }
+/// ```
+pub fn moment_of_inertia<M, R>(mass: M, radius: R) -> Prod<Prod<M, R>, R>
+where
+    M: Mass + Mul<R>,
+    R: Length + Copy,
+    Prod<M, R>: Mul<R>,
+{
+    mass * radius * radius
+}
+
+/// Compute the angular acceleration of a rigid body with moment of inertia `inertia` that is
+/// subject to a torque `torque`, as `torque / inertia`.
+///
+/// As angles are dimensionless, the result has units of reciprocal time squared.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::angular_acceleration;
+/// use dim::si;
+///
+/// fn main() {
+///     let tau = 6.0 * si::N * si::M;
+///     let i = 2.0 * si::KG * si::M2;
+///
+///     assert_eq!(angular_acceleration(tau, i), 3.0 * si::RAD / si::S2);
+/// }
+/// ```
+///
+/// The moment of inertia must have units of mass times length squared:
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::angular_acceleration;
+/// use dim::si;
+///
+/// fn main() {
+///     let alpha = angular_acceleration(6.0 * si::N * si::M, 2.0 * si::S);
+/// }
+/// ```
+pub fn angular_acceleration<T, I>(torque: T, inertia: I) -> Quot<T, I>
+where
+    T: Energy + Div<I>,
+    I: MomentOfInertia,
+    Quot<T, I>: AngularAcceleration,
+{
+    torque / inertia
+}
+
+/// Compute the density of a body of mass `mass` occupying a volume `volume`, as `mass / volume`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::density;
+/// use dim::si;
+///
+/// fn main() {
+///     let m = 3.0 * si::KG;
+///     let v = 0.002 * si::M3;
+///
+///     assert_eq!(density(m, v), 1500.0 * si::KG / si::M3);
+/// }
+/// ```
+pub fn density<M, V>(mass: M, volume: V) -> Quot<M, V>
+where
+    M: Mass + Div<V>,
+    V: Volume,
+{
+    mass / volume
+}
+
+/// Compute the mass of a body of density `density` occupying a volume `volume`, as
+/// `density * volume`. This is the inverse of `density`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::mass_from_density_volume;
+/// use dim::si;
+///
+/// fn main() {
+///     let rho = 1000.0 * si::KG / si::M3;
+///     let v = 0.002 * si::M3;
+///
+///     assert_eq!(mass_from_density_volume(rho, v), 2.0 * si::KG);
+/// }
+/// ```
+pub fn mass_from_density_volume<D, V>(density: D, volume: V) -> Prod<D, V>
+where
+    D: Mul<V>,
+    V: Volume,
+    Prod<D, V>: Mass,
+{
+    density * volume
+}
+
+/// Compute the conductance of a conductor with resistance `resistance`, as `1 / resistance`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::conductance;
+/// use dim::si;
+///
+/// fn main() {
+///     let g: si::Siemens<f64> = conductance(4.0 * si::OHM);
+///     assert_eq!(g, 0.25 * si::SIE);
+/// }
+/// ```
+pub fn conductance<R>(resistance: R) -> R::Output
+where
+    R: Resistance + Recip,
+    R::Output: Conductance,
+{
+    resistance.recip()
+}
+
+/// Compute the resistance of a conductor with conductance `conductance`, as `1 / conductance`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::resistance;
+/// use dim::si;
+///
+/// fn main() {
+///     let r: si::Ohm<f64> = resistance(0.25 * si::SIE);
+///     assert_eq!(r, 4.0 * si::OHM);
+/// }
+/// ```
+pub fn resistance<G>(conductance: G) -> G::Output
+where
+    G: Conductance + Recip,
+    G::Output: Resistance,
+{
+    conductance.recip()
+}
+
+fn exp(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return x.exp();
+    #[cfg(not(feature = "std"))]
+    return unsafe { core::intrinsics::expf64(x) };
+}
+
+/// Compute the factor by which an exponentially decaying quantity with time constant `tau` has
+/// shrunk after a time `t`, `exp(-t/tau)`.
+///
+/// Multiplying a quantity by this factor keeps its units, as it is dimensionless.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::exp_decay;
+/// use dim::si;
+///
+/// fn main() {
+///     let v0 = 5.0 * si::V;
+///     let tau = 2.0 * si::S;
+///
+///     assert_eq!(v0 * exp_decay(0.0 * si::S, tau), v0);
+///     assert_eq!(v0 * exp_decay(tau, tau), v0 * (-1.0f64).exp());
+/// }
+/// ```
+pub fn exp_decay<T>(t: T, tau: T) -> f64
+where
+    T: Time + Dimensioned<Value = f64>,
+{
+    exp(-t.value_unsafe() / tau.value_unsafe())
+}
+
+/// Compute the factor by which an exponentially growing quantity with time constant `tau` has
+/// grown after a time `t`, `exp(t/tau)`.
+///
+/// Multiplying a quantity by this factor keeps its units, as it is dimensionless.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::physics::exp_growth;
+/// use dim::si;
+///
+/// fn main() {
+///     let n0 = 100.0 * si::MOL;
+///     let tau = 3.0 * si::S;
+///
+///     assert_eq!(n0 * exp_growth(tau, tau), n0 * 1.0f64.exp());
+/// }
+/// ```
+pub fn exp_growth<T>(t: T, tau: T) -> f64
+where
+    T: Time + Dimensioned<Value = f64>,
+{
+    exp(t.value_unsafe() / tau.value_unsafe())
+}
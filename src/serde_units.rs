@@ -0,0 +1,142 @@
+//! Serializing quantities along with their units
+//!
+//! With the `serde` feature, the unit systems that come with dimensioned serialize a quantity as
+//! its raw value, so the units are only known from the type it is read back into. The functions
+//! here instead write a struct with the value and a string of its units, such as
+//! `{"value": 9.8, "units": "m*s^-2"}`. When reading it back, the units are checked against those
+//! of the type being deserialized, and a mismatch is an error rather than a silent conversion.
+//!
+//! They are meant to be used with serde's `with` attribute, as
+//! `#[serde(with = "dim::serde_units")]`, or called directly.
+//!
+//! The units are written as the print token and exponent of each base unit, as given by
+//! `PrintUnits`, joined by `*`. A fractional exponent is written as a ratio, as in `cm^1/2`.
+//!
+//! # Example
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Projectile {
+//!     #[serde(with = "dim::serde_units")]
+//!     position: si::Meter<f64>,
+//!     #[serde(with = "dim::serde_units")]
+//!     velocity: si::MeterPerSecond<f64>,
+//! }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use fmt::PrintUnits;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use Dimensioned;
+
+const FIELDS: &[&str] = &["value", "units"];
+
+/// Give the units of `Q` as they are written by `serialize`.
+///
+/// Quantities without units give an empty string.
+pub fn units_string<Q: PrintUnits>() -> String {
+    use core::fmt::Write;
+
+    let mut units = String::new();
+    Q::for_each_unit(|token, numer, denom| {
+        if !units.is_empty() {
+            units.push('*');
+        }
+        units.push_str(token);
+        match (numer, denom) {
+            (1, 1) => Ok(()),
+            (_, 1) => write!(units, "^{}", numer),
+            _ => write!(units, "^{}/{}", numer, denom),
+        }
+    })
+    .expect("writing to a String cannot fail");
+    units
+}
+
+/// Serialize `quantity` as a struct with its value and its units.
+pub fn serialize<Q, S>(quantity: &Q, serializer: S) -> Result<S::Ok, S::Error>
+where
+    Q: Dimensioned + PrintUnits,
+    Q::Value: Serialize,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct("Quantity", 2)?;
+    state.serialize_field("value", quantity.value_unsafe())?;
+    state.serialize_field("units", &units_string::<Q>())?;
+    state.end()
+}
+
+/// Deserialize a quantity written by `serialize`, failing if its units are not those of `Q`.
+pub fn deserialize<'de, Q, D>(deserializer: D) -> Result<Q, D::Error>
+where
+    Q: Dimensioned + PrintUnits,
+    Q::Value: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct("Quantity", FIELDS, QuantityVisitor(PhantomData))
+}
+
+struct QuantityVisitor<Q>(PhantomData<Q>);
+
+impl<Q> QuantityVisitor<Q>
+where
+    Q: Dimensioned + PrintUnits,
+{
+    fn check<E: de::Error>(value: Q::Value, units: &str) -> Result<Q, E> {
+        let expected = units_string::<Q>();
+        if units == expected {
+            Ok(Q::new(value))
+        } else {
+            Err(E::custom(format_args!(
+                "expected units `{}`, found `{}`",
+                expected, units
+            )))
+        }
+    }
+}
+
+impl<'de, Q> Visitor<'de> for QuantityVisitor<Q>
+where
+    Q: Dimensioned + PrintUnits,
+    Q::Value: Deserialize<'de>,
+{
+    type Value = Q;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a quantity with units `{}`", units_string::<Q>())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Q, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let units: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::check(value, &units)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Q, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut value = None;
+        let mut units: Option<String> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "value" if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                "value" => value = Some(map.next_value()?),
+                "units" if units.is_some() => return Err(de::Error::duplicate_field("units")),
+                "units" => units = Some(map.next_value()?),
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let units = units.ok_or_else(|| de::Error::missing_field("units"))?;
+        Self::check(value, &units)
+    }
+}
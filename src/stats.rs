@@ -0,0 +1,264 @@
+//! Statistics for quantities
+//!
+//! The functions in this module are generic with regards to unit system. They work with any
+//! quantity that has a value type of `f64`, and give results with the same units as their inputs.
+
+use core::marker::PhantomData;
+use Dimensioned;
+
+/// Compute the weighted mean of some quantities.
+///
+/// Each sample is a pair of a quantity and a dimensionless weight, and the result is
+/// `Σ(value * weight) / Σweight`. Returns `None` if there are no samples or the total weight is
+/// zero.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+/// use dim::stats::weighted_mean;
+///
+/// fn main() {
+///     let readings = [(2.0 * si::M, 1.0), (4.0 * si::M, 3.0)];
+///     assert_eq!(weighted_mean(&readings), Some(3.5 * si::M));
+///
+///     let none: [(si::Meter<f64>, f64); 0] = [];
+///     assert_eq!(weighted_mean(&none), None);
+/// }
+/// ```
+pub fn weighted_mean<Q>(samples: &[(Q, f64)]) -> Option<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    let (sum, total_weight) = samples
+        .iter()
+        .fold((0.0, 0.0), |(sum, total_weight), &(ref q, weight)| {
+            (sum + q.value_unsafe() * weight, total_weight + weight)
+        });
+
+    if total_weight == 0.0 {
+        None
+    } else {
+        Some(Q::new(sum / total_weight))
+    }
+}
+
+fn ln(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return x.ln();
+    #[cfg(not(feature = "std"))]
+    return unsafe { core::intrinsics::logf64(x) };
+}
+
+fn exp(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    return x.exp();
+    #[cfg(not(feature = "std"))]
+    return unsafe { core::intrinsics::expf64(x) };
+}
+
+/// Compute the geometric mean of some quantities, the `n`th root of their product.
+///
+/// The product of `n` quantities with units `U` has units `U^n`, and its `n`th root has units `U`
+/// again. As `n` is only known at runtime, this can't be tracked with type-level integers;
+/// instead, the mean is computed from the values alone, as the exponential of the mean of their
+/// logarithms, and given the units of the inputs. This also avoids overflowing the product.
+///
+/// Returns `None` if there are no quantities. If any are zero, the mean is zero, and if any are
+/// negative, it is `NaN`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+/// use dim::stats::geometric_mean;
+///
+/// fn main() {
+///     let sides = [2.0 * si::M, 8.0 * si::M];
+///     assert_eq!(geometric_mean(&sides), Some(4.0 * si::M));
+///
+///     let none: [si::Meter<f64>; 0] = [];
+///     assert_eq!(geometric_mean(&none), None);
+/// }
+/// ```
+pub fn geometric_mean<Q>(quantities: &[Q]) -> Option<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    if quantities.is_empty() {
+        return None;
+    }
+    let log_sum = quantities
+        .iter()
+        .fold(0.0, |sum, q| sum + ln(*q.value_unsafe()));
+    Some(Q::new(exp(log_sum / quantities.len() as f64)))
+}
+
+/// A key for sorting floats by the total order of IEEE 754, as `f64::total_cmp` does.
+///
+/// Negative `NaN`s sort first and positive ones last, so they end up at the ends of a sorted slice.
+fn total_order_key(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    bits ^ (((bits >> 63) as u64) >> 1) as i64
+}
+
+/// Compute the `p`th percentile of some quantities, for `p` from 0 to 100.
+///
+/// The quantities are sorted in place, and the percentile is interpolated linearly between the two
+/// closest ranks, so the 0th percentile is the smallest quantity and the 100th is the largest.
+/// Returns `None` if there are no quantities or `p` is outside of `[0, 100]`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+/// use dim::stats::percentile;
+///
+/// fn main() {
+///     let mut latencies = [4.0 * si::S, 1.0 * si::S, 3.0 * si::S, 2.0 * si::S, 5.0 * si::S];
+///     assert_eq!(percentile(&mut latencies, 75.0), Some(4.0 * si::S));
+///     assert_eq!(percentile(&mut latencies, 90.0), Some(4.6 * si::S));
+///     assert_eq!(percentile(&mut latencies, 101.0), None);
+/// }
+/// ```
+pub fn percentile<Q>(quantities: &mut [Q], p: f64) -> Option<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    if quantities.is_empty() || !(0.0 <= p && p <= 100.0) {
+        return None;
+    }
+    quantities.sort_unstable_by_key(|q| total_order_key(*q.value_unsafe()));
+
+    let rank = p / 100.0 * (quantities.len() - 1) as f64;
+    let below = rank as usize;
+    let lower = *quantities[below].value_unsafe();
+    if below + 1 == quantities.len() {
+        return Some(Q::new(lower));
+    }
+    let upper = *quantities[below + 1].value_unsafe();
+    Some(Q::new(lower + (upper - lower) * (rank - below as f64)))
+}
+
+/// Compute the median of some quantities.
+///
+/// The quantities are sorted in place. For an even number of quantities, this is the mean of the
+/// two in the middle. Returns `None` if there are no quantities.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+/// use dim::stats::median;
+///
+/// fn main() {
+///     let mut lengths = [3.0 * si::M, 1.0 * si::M, 2.0 * si::M];
+///     assert_eq!(median(&mut lengths), Some(2.0 * si::M));
+///
+///     let mut lengths = [4.0 * si::M, 1.0 * si::M, 2.0 * si::M, 3.0 * si::M];
+///     assert_eq!(median(&mut lengths), Some(2.5 * si::M));
+/// }
+/// ```
+pub fn median<Q>(quantities: &mut [Q]) -> Option<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    percentile(quantities, 50.0)
+}
+
+/// Tracks the running minimum and maximum of a stream of quantities.
+///
+/// Values that are `NaN` are ignored, so one bad sample does not hide the extremes of the rest.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+/// use dim::stats::MinMax;
+///
+/// fn main() {
+///     let mut temperatures = MinMax::new();
+///     assert_eq!(temperatures.range(), None);
+///
+///     temperatures.push(293.0 * si::K);
+///     temperatures.extend(vec![288.5 * si::K, 301.0 * si::K]);
+///
+///     assert_eq!(temperatures.min(), Some(288.5 * si::K));
+///     assert_eq!(temperatures.max(), Some(301.0 * si::K));
+///     assert_eq!(temperatures.range(), Some(12.5 * si::K));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MinMax<Q> {
+    extremes: Option<(f64, f64)>,
+    _marker: PhantomData<Q>,
+}
+
+impl<Q> MinMax<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    /// Create a tracker that has not seen any quantities.
+    pub fn new() -> Self {
+        MinMax {
+            extremes: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Add a quantity to the stream.
+    pub fn push(&mut self, quantity: Q) {
+        let x = *quantity.value_unsafe();
+        if x.is_nan() {
+            return;
+        }
+        self.extremes = Some(match self.extremes {
+            None => (x, x),
+            Some((min, max)) => (
+                if x < min { x } else { min },
+                if x > max { x } else { max },
+            ),
+        });
+    }
+
+    /// The smallest quantity seen so far, or `None` if there have been none.
+    pub fn min(&self) -> Option<Q> {
+        self.extremes.map(|(min, _)| Q::new(min))
+    }
+
+    /// The largest quantity seen so far, or `None` if there have been none.
+    pub fn max(&self) -> Option<Q> {
+        self.extremes.map(|(_, max)| Q::new(max))
+    }
+
+    /// The difference between the largest and smallest quantities seen so far, or `None` if there
+    /// have been none.
+    pub fn range(&self) -> Option<Q> {
+        self.extremes.map(|(min, max)| Q::new(max - min))
+    }
+}
+
+impl<Q> Default for MinMax<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    fn default() -> Self {
+        MinMax::new()
+    }
+}
+
+impl<Q> Extend<Q> for MinMax<Q>
+where
+    Q: Dimensioned<Value = f64>,
+{
+    fn extend<I: IntoIterator<Item = Q>>(&mut self, iter: I) {
+        for quantity in iter {
+            self.push(quantity);
+        }
+    }
+}
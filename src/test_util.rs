@@ -0,0 +1,56 @@
+//! Tools for property testing code that uses dimensioned
+//!
+//! This module is only available with the `quickcheck` feature. With it enabled, all quantities
+//! whose value types implement `quickcheck::Arbitrary` implement it as well, so they may be used
+//! directly as arguments to properties. The functions here are for when you need more control over
+//! the values generated, such as when implementing `Arbitrary` for your own types.
+//!
+//! # Example
+//! ```rust
+//! extern crate dimensioned as dim;
+//! extern crate quickcheck;
+//!
+//! use dim::si;
+//! use dim::test_util::bounded;
+//! use quickcheck::{quickcheck, Arbitrary, Gen};
+//!
+//! #[derive(Clone, Debug)]
+//! struct Wheel {
+//!     radius: si::Meter<f64>,
+//! }
+//!
+//! impl Arbitrary for Wheel {
+//!     fn arbitrary<G: Gen>(g: &mut G) -> Self {
+//!         Wheel { radius: bounded(g, 2.0) }
+//!     }
+//! }
+//!
+//! fn main() {
+//!     use dim::Abs;
+//!     fn fits(w: Wheel) -> bool {
+//!         w.radius.abs() <= 2.0 * si::M
+//!     }
+//!     quickcheck(fits as fn(Wheel) -> bool);
+//! }
+//! ```
+
+use quickcheck::Gen;
+use Dimensioned;
+
+/// Generate a quantity whose value lies in the range `[-magnitude, magnitude)`.
+pub fn bounded<Q, G>(g: &mut G, magnitude: f64) -> Q
+where
+    Q: Dimensioned<Value = f64>,
+    G: Gen,
+{
+    Q::new(g.gen_range(-magnitude, magnitude))
+}
+
+/// Generate a quantity whose value lies in the range `[0, magnitude)`.
+pub fn bounded_nonnegative<Q, G>(g: &mut G, magnitude: f64) -> Q
+where
+    Q: Dimensioned<Value = f64>,
+    G: Gen,
+{
+    Q::new(g.gen_range(0.0, magnitude))
+}
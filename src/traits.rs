@@ -24,6 +24,98 @@ pub trait Dimensioned {
 
 /// This trait is implemented for all quantities with no units. The unit systems that come with
 /// dimensioned use `Unitless<V>` for that type.
+///
+/// A unitless quantity of a primitive number type, such as `Unitless<f64>` or `Unitless<i32>`, may
+/// also be converted back into a bare number with `From` or `Into`, and a `Unitless<f32>` or
+/// `Unitless<f64>` compared with a bare float using `==`. Quantities with units may not:
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let x: f64 = (3.0 * si::M).into();
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     assert!(3.0 * si::M == 3.0);
+/// }
+/// ```
+///
+/// With `std`, unitless quantities of `f32` and `f64` also have the trigonometric methods `sin`,
+/// `cos`, `tan`, `asin`, `acos`, and `atan`, which give unitless quantities. Those with units don't:
+///
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let s: si::Unitless<f64> = (0.0 * si::ONE).sin();
+///     assert_eq!(s, 0.0);
+///
+///     // Dereference first to call the method of the value instead
+///     let c: f64 = (*(0.0 * si::ONE)).cos();
+///     assert_eq!(c, 1.0);
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let s = (1.0 * si::M).sin();
+/// }
+/// ```
+///
+/// The same goes for `powf`, as the units of a quantity with units raised to an arbitrary power
+/// can't be known:
+///
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let x = (8.0 * si::ONE).powf(1.0 / 3.0);
+///     assert!((x - 2.0).abs() < 1e-12);
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let x = (8.0 * si::M).powf(1.0 / 3.0);
+/// }
+/// ```
+///
+/// Nor do they have `exp`, `ln`, `log10`, or `log2`:
+///
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let e = (1.0 * si::ONE).exp();
+///     assert!((e - std::f64::consts::E).abs() < 1e-12);
+///     assert_eq!((1000.0 * si::ONE).log10(), 3.0);
+/// }
+/// ```
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     let x = (1.0 * si::M).ln();
+/// }
+/// ```
 pub trait Dimensionless: Dimensioned {
     /// Extract the value from a quantity with no units. As there are no units to ignore, it is
     /// dimensionally safe.
@@ -94,20 +186,27 @@ pub trait MapUnsafe<ValueOut, UnitsOut>: Dimensioned {
 /// perfectly safe to use.
 ///
 /// It can be used similarly to `MapUnsafe`, but only for `Dimensionless` quantities, and it cannot
-/// make them non-`Dimensionless`.
+/// make them non-`Dimensionless`. The unit systems made with `make_units!` also have an inherent
+/// `map` method for quantities with any units, which keeps their units; this trait is for generic
+/// code, where the value may be changed arbitrarily as there are no units to keep.
 ///
 /// # Example
 ///
 /// ```rust
 /// extern crate dimensioned as dim;
 ///
+/// use dim::Map;
+///
+/// fn sine<Q: Map<f64, Value = f64>>(x: Q) -> Q::Output {
+///     x.map(|v| v.sin())
+/// }
+///
 /// fn main() {
 ///     use dim::si;
 ///     let x1 = 2.0 * si::ONE;
 ///     let x2 = 2.0f64;
 ///
-///     use dim::Map;
-///     assert_eq!(x1.map(|v| v.sin()), x2.sin() * si::ONE);
+///     assert_eq!(sine(x1), x2.sin() * si::ONE);
 /// }
 /// ```
 pub trait Map<ValueOut>: Dimensionless {
@@ -118,6 +217,91 @@ pub trait Map<ValueOut>: Dimensionless {
     fn map<F: FnOnce(Self::Value) -> ValueOut>(self, f: F) -> Self::Output;
 }
 
+/// Convert a bare float or a quantity with no units into the unitless quantity `Q`.
+///
+/// This allows functions to accept either form uniformly. It is implemented for `f32` and `f64`,
+/// for any `Q` with a matching value type, and for every `Dimensionless` quantity, into itself.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::si;
+/// use dim::IntoDimensionless;
+///
+/// fn double<X: IntoDimensionless<si::Unitless<f64>>>(x: X) -> si::Unitless<f64> {
+///     2.0 * x.into_dimensionless()
+/// }
+///
+/// fn main() {
+///     assert_eq!(double(3.0), 6.0 * si::ONE);
+///     assert_eq!(double(3.0 * si::ONE), 6.0 * si::ONE);
+///     assert_eq!(double(3.0 * si::M / si::M), 6.0 * si::ONE);
+/// }
+/// ```
+pub trait IntoDimensionless<Q: Dimensionless> {
+    /// Perform the conversion
+    fn into_dimensionless(self) -> Q;
+}
+
+impl<Q: Dimensionless> IntoDimensionless<Q> for Q {
+    #[inline]
+    fn into_dimensionless(self) -> Q {
+        self
+    }
+}
+
+impl<Q: Dimensionless<Value = f32>> IntoDimensionless<Q> for f32 {
+    #[inline]
+    fn into_dimensionless(self) -> Q {
+        Q::new(self)
+    }
+}
+
+impl<Q: Dimensionless<Value = f64>> IntoDimensionless<Q> for f64 {
+    #[inline]
+    fn into_dimensionless(self) -> Q {
+        Q::new(self)
+    }
+}
+
+/// Implemented when two unit types are exactly the same.
+///
+/// As the type-level arithmetic always produces the same type for equal units, this is only
+/// implemented for a type with itself. Its use is as a bound in generic code, where the compiler
+/// cannot yet see that two unit types are equal, such as by the `cast_units` member of unit systems.
+///
+/// If you need to change the units of a quantity without this check, use `MapUnsafe`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// use dim::si::{self, SI};
+/// use dim::typenum::{Diff, Sum};
+/// use dim::SameUnits;
+/// use std::ops::{Add, Sub};
+///
+/// // Without the cast, this would not compile, as the compiler cannot tell that the units of
+/// // `x * y / y` are the units of `x` for arbitrary units.
+/// fn mul_div<U1, U2>(x: SI<f64, U1>, y: SI<f64, U2>) -> SI<f64, U1>
+/// where
+///     U1: Add<U2>,
+///     U2: Copy,
+///     Sum<U1, U2>: Sub<U2>,
+///     Diff<Sum<U1, U2>, U2>: SameUnits<U1>,
+/// {
+///     (x * y / y).cast_units()
+/// }
+///
+/// fn main() {
+///     assert_eq!(mul_div(3.0 * si::M, 2.0 * si::S), 3.0 * si::M);
+/// }
+/// ```
+pub trait SameUnits<U> {}
+
+impl<U> SameUnits<U> for U {}
+
 #[cfg(feature = "oibit")]
 /// Everything that is not a quantity implements this trait
 pub auto trait NotDim {}
@@ -198,6 +382,747 @@ impl_abs!(i32);
 impl_abs!(i64);
 impl_abs!(isize);
 
+/// `MaxZero` is used for implementing a `max_zero()` member, which clamps negative values to zero.
+///
+/// This is useful for quantities that cannot physically be negative, such as mass, where rounding
+/// errors in a simulation may otherwise produce nonphysical results. A `NaN` is returned unchanged.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::MaxZero;
+///     assert_eq!((-2.0 * si::KG).max_zero(), 0.0 * si::KG);
+///     assert_eq!((3.0 * si::KG).max_zero(), 3.0 * si::KG);
+/// }
+/// ```
+pub trait MaxZero {
+    /// The method for clamping to be nonnegative
+    fn max_zero(self) -> Self;
+}
+
+macro_rules! impl_max_zero {
+    ($t:ty, $zero:expr) => {
+        impl MaxZero for $t {
+            fn max_zero(self) -> Self {
+                if self < $zero {
+                    $zero
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+impl_max_zero!(f32, 0.0);
+impl_max_zero!(f64, 0.0);
+impl_max_zero!(i8, 0);
+impl_max_zero!(i16, 0);
+impl_max_zero!(i32, 0);
+impl_max_zero!(i64, 0);
+impl_max_zero!(isize, 0);
+
+/// `ClampAbs` is used for implementing a `clamp_abs()` member, which clamps a value to the range
+/// `[-limit, limit]`.
+///
+/// This is useful for limiting signed quantities, such as forces or velocities, symmetrically
+/// about zero. The sign of `limit` is ignored, and a `NaN` is returned unchanged. For quantities,
+/// `limit` must have the same units as the value being clamped.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::ClampAbs;
+///     let limit = 2.0 * si::MPS;
+///
+///     assert_eq!((3.0 * si::MPS).clamp_abs(limit), 2.0 * si::MPS);
+///     assert_eq!((-3.0 * si::MPS).clamp_abs(limit), -2.0 * si::MPS);
+///     assert_eq!((1.0 * si::MPS).clamp_abs(limit), 1.0 * si::MPS);
+/// }
+/// ```
+pub trait ClampAbs: Sized {
+    /// The method for clamping to `[-limit, limit]`
+    fn clamp_abs(self, limit: Self) -> Self {
+        self.clamp_abs_saturated(limit).0
+    }
+
+    /// Clamp to `[-limit, limit]`, also giving whether the value was outside that range.
+    ///
+    /// This is useful for controllers that need to know when their output saturates, such as to
+    /// implement anti-windup.
+    ///
+    /// # Example
+    /// ```rust
+    /// extern crate dimensioned as dim;
+    /// use dim::si;
+    ///
+    /// fn main() {
+    ///     use dim::ClampAbs;
+    ///     let limit = 5.0 * si::N;
+    ///
+    ///     assert_eq!((8.0 * si::N).clamp_abs_saturated(limit), (5.0 * si::N, true));
+    ///     assert_eq!((-3.0 * si::N).clamp_abs_saturated(limit), (-3.0 * si::N, false));
+    /// }
+    /// ```
+    fn clamp_abs_saturated(self, limit: Self) -> (Self, bool);
+}
+
+macro_rules! impl_clamp_abs {
+    ($t:ty) => {
+        impl ClampAbs for $t {
+            fn clamp_abs_saturated(self, limit: Self) -> (Self, bool) {
+                let limit = Abs::abs(limit);
+                if self > limit {
+                    (limit, true)
+                } else if self < -limit {
+                    (-limit, true)
+                } else {
+                    (self, false)
+                }
+            }
+        }
+    };
+}
+impl_clamp_abs!(f32);
+impl_clamp_abs!(f64);
+impl_clamp_abs!(i8);
+impl_clamp_abs!(i16);
+impl_clamp_abs!(i32);
+impl_clamp_abs!(i64);
+impl_clamp_abs!(isize);
+
+use core::ops::Sub;
+/// `NearestIn` is used for implementing a `nearest_in()` member, which snaps a value to the
+/// closest of a set of allowed values.
+///
+/// This is useful for choosing from discrete values, such as standard component sizes. Closeness
+/// is measured by absolute difference, and ties go to the value that comes first in the set. For
+/// quantities, the allowed values must have the same units as the value being snapped.
+///
+/// It is implemented for everything that can be subtracted, compared, and has an absolute value.
+///
+/// # Panics
+///
+/// Panics if `allowed` is empty.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::NearestIn;
+///     let allowed = [1.0 * si::OHM, 2.2 * si::OHM, 4.7 * si::OHM];
+///
+///     assert_eq!((3.0 * si::OHM).nearest_in(&allowed), 2.2 * si::OHM);
+///     assert_eq!((9.0 * si::OHM).nearest_in(&allowed), 4.7 * si::OHM);
+/// }
+/// ```
+pub trait NearestIn: Sized {
+    /// The method for finding the closest allowed value
+    fn nearest_in(self, allowed: &[Self]) -> Self;
+}
+
+impl<T> NearestIn for T
+where
+    T: Copy + Sub<Output = T> + Abs + PartialOrd,
+{
+    fn nearest_in(self, allowed: &[Self]) -> Self {
+        let (&first, rest) = allowed
+            .split_first()
+            .expect("nearest_in requires at least one allowed value");
+        let distance = |other: T| Abs::abs(self - other);
+
+        rest.iter()
+            .fold((first, distance(first)), |(best, best_distance), &other| {
+                let d = distance(other);
+                if d < best_distance {
+                    (other, d)
+                } else {
+                    (best, best_distance)
+                }
+            })
+            .0
+    }
+}
+
+/// `NearZero` is used for implementing an `is_near_zero()` member, which checks whether a value is
+/// within a tolerance of zero.
+///
+/// For quantities, the tolerance must have the same units as the value, so that the comparison is
+/// dimensionally meaningful. A `NaN` is never near zero.
+///
+/// It is implemented for everything that has an absolute value and can be compared.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::NearZero;
+///     let tolerance = 1e-6 * si::M;
+///
+///     assert!((-5e-7 * si::M).is_near_zero(tolerance));
+///     assert!(!(2e-6 * si::M).is_near_zero(tolerance));
+/// }
+/// ```
+pub trait NearZero {
+    /// The method for checking whether `|self| <= tolerance`
+    fn is_near_zero(self, tolerance: Self) -> bool;
+}
+
+impl<T> NearZero for T
+where
+    T: Abs + PartialOrd,
+{
+    fn is_near_zero(self, tolerance: Self) -> bool {
+        Abs::abs(self) <= tolerance
+    }
+}
+
+/// `ClampDeadzone` is used for implementing a `clamp_magnitude_with_deadzone()` member, which
+/// conditions an input by zeroing it within a deadzone and clamping it to a maximum magnitude.
+///
+/// This is a common treatment for joystick and sensor readings, where small values are noise and
+/// large ones must be limited. A value with `|self| < deadzone` becomes zero, and any other value
+/// is clamped to `[-max, max]` as by `ClampAbs`. For quantities, the deadzone and maximum must have
+/// the same units as the value. A `NaN` is returned unchanged.
+///
+/// It is implemented for the primitive signed number types and for quantities of them.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::ClampDeadzone;
+///     let (deadzone, max) = (0.1 * si::MPS, 2.0 * si::MPS);
+///     let condition = |v: si::MeterPerSecond<f64>| v.clamp_magnitude_with_deadzone(deadzone, max);
+///
+///     assert_eq!(condition(0.05 * si::MPS), 0.0 * si::MPS);
+///     assert_eq!(condition(-1.5 * si::MPS), -1.5 * si::MPS);
+///     assert_eq!(condition(3.0 * si::MPS), 2.0 * si::MPS);
+/// }
+/// ```
+pub trait ClampDeadzone: Sized {
+    /// The method for zeroing `|self| < deadzone` and clamping the rest to `[-max, max]`
+    fn clamp_magnitude_with_deadzone(self, deadzone: Self, max: Self) -> Self;
+}
+
+macro_rules! impl_clamp_deadzone {
+    ($t:ty, $zero:expr) => {
+        impl ClampDeadzone for $t {
+            fn clamp_magnitude_with_deadzone(self, deadzone: Self, max: Self) -> Self {
+                if Abs::abs(self) < deadzone {
+                    $zero
+                } else {
+                    self.clamp_abs(max)
+                }
+            }
+        }
+    };
+}
+impl_clamp_deadzone!(f32, 0.0);
+impl_clamp_deadzone!(f64, 0.0);
+impl_clamp_deadzone!(i8, 0);
+impl_clamp_deadzone!(i16, 0);
+impl_clamp_deadzone!(i32, 0);
+impl_clamp_deadzone!(i64, 0);
+impl_clamp_deadzone!(isize, 0);
+
+/// `InRange` is used for implementing an `in_range()` member, which checks whether a value lies
+/// within an inclusive range.
+///
+/// For quantities, the bounds of the range must have the same units as the value. As quantities
+/// implement `PartialOrd`, they may also be used with `RangeInclusive::contains`, which requires
+/// Rust 1.35.0.
+///
+/// It is implemented for everything that can be compared.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::InRange;
+///     let x = 1.5 * si::M;
+///
+///     assert!(x.in_range(1.0 * si::M, 2.0 * si::M));
+///     assert!(!x.in_range(2.0 * si::M, 3.0 * si::M));
+/// }
+/// ```
+pub trait InRange {
+    /// The method for checking whether `lo <= self <= hi`
+    fn in_range(&self, lo: Self, hi: Self) -> bool;
+}
+
+impl<T: PartialOrd> InRange for T {
+    fn in_range(&self, lo: Self, hi: Self) -> bool {
+        lo <= *self && *self <= hi
+    }
+}
+
+/// Which bound, if any, a value was clamped to by `ClampReporting`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Clamped {
+    /// The value was within the range, and is unchanged.
+    None,
+    /// The value was below the range, and was raised to its lower bound.
+    Low,
+    /// The value was above the range, and was lowered to its upper bound.
+    High,
+}
+
+/// `ClampReporting` is used for implementing a `clamp_reporting()` member, which restricts a value
+/// to the range `[lo, hi]`, and reports which bound, if either, it was clamped to.
+///
+/// This is useful for validation, where an out of range input should be both corrected and
+/// reported. For quantities, the bounds must have the same units as the value. A `NaN` is returned
+/// unchanged, and is not reported as clamped.
+///
+/// It is implemented for everything that can be compared.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::{ClampReporting, Clamped};
+///     let (lo, hi) = (0.0 * si::A, 2.0 * si::A);
+///
+///     assert_eq!((1.0 * si::A).clamp_reporting(lo, hi), (1.0 * si::A, Clamped::None));
+///     assert_eq!((3.0 * si::A).clamp_reporting(lo, hi), (2.0 * si::A, Clamped::High));
+/// }
+/// ```
+pub trait ClampReporting: Sized {
+    /// The method for clamping to `[lo, hi]`, reporting which bound was used
+    fn clamp_reporting(self, lo: Self, hi: Self) -> (Self, Clamped);
+}
+
+impl<T: PartialOrd> ClampReporting for T {
+    fn clamp_reporting(self, lo: Self, hi: Self) -> (Self, Clamped) {
+        if self < lo {
+            (lo, Clamped::Low)
+        } else if self > hi {
+            (hi, Clamped::High)
+        } else {
+            (self, Clamped::None)
+        }
+    }
+}
+
+/// `ClampOr` is used for implementing a `clamp_or()` member, which restricts a value to the range
+/// `[lo, hi]`, or gives `fallback` if that is not possible.
+///
+/// Where `clamp` would panic, when either bound is `NaN` or `lo > hi`, this gives `fallback`
+/// instead, as it does when the value itself is `NaN`. This makes it robust to unreliable inputs,
+/// such as sensor data. For quantities, the bounds and the fallback must have the same units as the
+/// value.
+///
+/// It is implemented for everything that can be compared; a value is taken to be `NaN` if it can't
+/// be compared to itself.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::ClampOr;
+///     let (lo, hi, fallback) = (0.0 * si::K, 400.0 * si::K, 293.0 * si::K);
+///
+///     assert_eq!((500.0 * si::K).clamp_or(lo, hi, fallback), 400.0 * si::K);
+///     assert_eq!((std::f64::NAN * si::K).clamp_or(lo, hi, fallback), fallback);
+/// }
+/// ```
+pub trait ClampOr: Sized {
+    /// The method for clamping to `[lo, hi]`, giving `fallback` if any value is `NaN`
+    fn clamp_or(self, lo: Self, hi: Self, fallback: Self) -> Self;
+}
+
+impl<T: PartialOrd> ClampOr for T {
+    #[cfg_attr(feature = "cargo-clippy", allow(neg_cmp_op_on_partial_ord))]
+    fn clamp_or(self, lo: Self, hi: Self, fallback: Self) -> Self {
+        // Both checks fail when anything is NaN
+        if self.partial_cmp(&self).is_none() || !(lo <= hi) {
+            fallback
+        } else if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
+    }
+}
+
+use core::ops::Add;
+/// `Approach` is used for implementing an `approach()` member, which moves a value toward a
+/// target by at most a given step. This makes it a simple rate limiter, as is common in control
+/// loops and animation.
+///
+/// If the target is within `max_delta` of the value, the target itself is returned, so that it is
+/// reached exactly rather than overshot. The sign of `max_delta` is ignored. For quantities, the
+/// target and the step must have the same units as the value.
+///
+/// It is implemented for everything that has an absolute value and supports addition, subtraction,
+/// and comparison.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::Approach;
+///     let speed = 10.0 * si::MPS;
+///     let step = 2.0 * si::MPS;
+///
+///     assert_eq!(speed.approach(15.0 * si::MPS, step), 12.0 * si::MPS);
+///     assert_eq!(speed.approach(5.0 * si::MPS, step), 8.0 * si::MPS);
+///     assert_eq!(speed.approach(11.0 * si::MPS, step), 11.0 * si::MPS);
+/// }
+/// ```
+pub trait Approach {
+    /// The method for moving `self` toward `target` by at most `max_delta`
+    fn approach(self, target: Self, max_delta: Self) -> Self;
+}
+
+impl<T> Approach for T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Abs + PartialOrd,
+{
+    fn approach(self, target: Self, max_delta: Self) -> Self {
+        let max_delta = Abs::abs(max_delta);
+        if Abs::abs(target - self) <= max_delta {
+            target
+        } else if target > self {
+            self + max_delta
+        } else {
+            self - max_delta
+        }
+    }
+}
+
+/// `RemEuclid` is used for implementing a `rem_euclid()` member, which gives the Euclidean
+/// remainder.
+///
+/// Unlike the `%` operator, the result is always in the range `[0, rhs.abs())`, which makes it
+/// well suited to wrapping positions and angles. For quantities, both arguments must have the
+/// same units.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::RemEuclid;
+///     let x = -1.0 * si::M;
+///     let l = 4.0 * si::M;
+///
+///     assert_eq!(x % l, -1.0 * si::M);
+///     assert_eq!(x.rem_euclid(l), 3.0 * si::M);
+///     assert_eq!((-5.0 * si::M).rem_euclid(l), 3.0 * si::M);
+///     assert_eq!((5.0 * si::M).rem_euclid(l), 1.0 * si::M);
+/// }
+/// ```
+pub trait RemEuclid {
+    /// The method for taking the Euclidean remainder
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_rem_euclid {
+    ($t:ty, $zero:expr) => {
+        impl RemEuclid for $t {
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let r = self % rhs;
+                if r < $zero {
+                    r + Abs::abs(rhs)
+                } else {
+                    r
+                }
+            }
+        }
+    };
+}
+impl_rem_euclid!(f32, 0.0);
+impl_rem_euclid!(f64, 0.0);
+impl_rem_euclid!(i8, 0);
+impl_rem_euclid!(i16, 0);
+impl_rem_euclid!(i32, 0);
+impl_rem_euclid!(i64, 0);
+impl_rem_euclid!(isize, 0);
+
+/// `Signum` is used for implementing a `signum()` member, which gives the sign of a value as `-1`
+/// or `1`.
+///
+/// Like the `signum` function of the floating point types, `0.0` gives `1.0` and `-0.0` gives
+/// `-1.0`, and a `NaN` gives `NaN`. See `SignumStrict` for a sign that is zero for zero.
+///
+/// As a sign has no units, this gives a unitless result for quantities.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::Signum;
+///     assert_eq!((3.0 * si::M).signum(), 1.0 * si::ONE);
+///     assert_eq!((-3.0 * si::M).signum(), -1.0 * si::ONE);
+///     assert_eq!((-0.0 * si::M).signum(), -1.0 * si::ONE);
+/// }
+/// ```
+pub trait Signum {
+    /// The resulting type after taking the sign
+    type Output;
+
+    /// The method for taking the sign
+    fn signum(self) -> Self::Output;
+}
+
+macro_rules! impl_signum {
+    ($t:ty) => {
+        impl Signum for $t {
+            type Output = $t;
+            fn signum(self) -> Self::Output {
+                if self.is_nan() {
+                    self
+                } else if self.is_sign_negative() {
+                    -1.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    };
+}
+impl_signum!(f32);
+impl_signum!(f64);
+
+/// `SignumStrict` is used for implementing a `signum_strict()` member, which gives the sign of a
+/// value as `-1`, `0`, or `1`.
+///
+/// This differs from the `signum` function of the floating point types in that both `0.0` and
+/// `-0.0` give `0.0`, which is useful when zero means "no direction". A `NaN` gives `NaN`.
+///
+/// As a sign has no units, this gives a unitless result for quantities.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::SignumStrict;
+///     assert_eq!((3.0 * si::M).signum_strict(), 1.0 * si::ONE);
+///     assert_eq!((-3.0 * si::M).signum_strict(), -1.0 * si::ONE);
+///     assert_eq!((0.0 * si::M).signum_strict(), 0.0 * si::ONE);
+///     assert_eq!((-0.0 * si::M).signum_strict(), 0.0 * si::ONE);
+///     assert_eq!((-0.0f64).signum(), -1.0);
+/// }
+/// ```
+pub trait SignumStrict {
+    /// The resulting type after taking the sign
+    type Output;
+
+    /// The method for taking the sign
+    fn signum_strict(self) -> Self::Output;
+}
+
+macro_rules! impl_signum_strict {
+    ($t:ty, $zero:expr, $one:expr) => {
+        impl SignumStrict for $t {
+            type Output = $t;
+            fn signum_strict(self) -> Self::Output {
+                if self > $zero {
+                    $one
+                } else if self < $zero {
+                    -$one
+                } else if self == $zero {
+                    $zero
+                } else {
+                    // NaN
+                    self
+                }
+            }
+        }
+    };
+}
+impl_signum_strict!(f32, 0.0, 1.0);
+impl_signum_strict!(f64, 0.0, 1.0);
+impl_signum_strict!(i8, 0, 1);
+impl_signum_strict!(i16, 0, 1);
+impl_signum_strict!(i32, 0, 1);
+impl_signum_strict!(i64, 0, 1);
+impl_signum_strict!(isize, 0, 1);
+
+/// `SigFigs` is used for implementing a `sig_figs()` member, which rounds a value to a number of
+/// significant figures.
+///
+/// Unlike the precision of a format string, which gives a number of decimal places, this keeps
+/// the same number of digits at any magnitude. The rounded value can then be printed as usual, so
+/// a quantity prints with its units. Zero, infinities, and `NaN` are returned unchanged, and
+/// asking for zero significant figures is treated as asking for one.
+///
+/// This is not currently implemented for built-in floating point types unless you are using `std`.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::SigFigs;
+///     #[cfg(feature = "std")]
+///     {
+///         assert_eq!(format!("{}", (1.23456 * si::M).sig_figs(3)), "1.23 m");
+///         assert_eq!(format!("{}", (12345.0 * si::M).sig_figs(3)), "12300 m");
+///     }
+/// }
+/// ```
+pub trait SigFigs {
+    /// The method for rounding to `digits` significant figures
+    fn sig_figs(self, digits: u32) -> Self;
+}
+
+macro_rules! impl_sig_figs {
+    ($t:ident) => {
+        #[cfg(feature = "std")]
+        impl SigFigs for $t {
+            fn sig_figs(self, digits: u32) -> Self {
+                if self == 0.0 || !self.is_finite() {
+                    return self;
+                }
+                let digits = digits.max(1) as i32;
+                // The power of ten that moves the last significant digit to the ones place
+                let shift = digits - 1 - self.abs().log10().floor() as i32;
+                let scale = (10.0 as $t).powi(shift.abs());
+                if shift < 0 {
+                    (self / scale).round() * scale
+                } else if scale.is_finite() {
+                    (self * scale).round() / scale
+                } else {
+                    // Values near the smallest normals need too large a scale, so split it
+                    let half = (10.0 as $t).powi(shift / 2);
+                    let rest = (10.0 as $t).powi(shift - shift / 2);
+                    (self * half * rest).round() / rest / half
+                }
+            }
+        }
+    };
+}
+impl_sig_figs!(f32);
+impl_sig_figs!(f64);
+
+/// `Log10Decades` is used for implementing a `log10_decades()` member, which gives the number of
+/// decades, or powers of ten, by which a value exceeds a reference, `log10(self / reference)`.
+///
+/// This is useful for placing quantities on a logarithmic axis. For quantities, the reference
+/// must have the same units as the value, and the result is a bare number.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::Log10Decades;
+///     let reference = 1e-3 * si::A;
+///
+///     assert_eq!((1.0 * si::A).log10_decades(reference), 3.0);
+///     assert_eq!((1e-5 * si::A).log10_decades(reference), -2.0);
+/// }
+/// ```
+pub trait Log10Decades {
+    /// The type of the number of decades
+    type Output;
+
+    /// The method for computing `log10(self / reference)`
+    fn log10_decades(self, reference: Self) -> Self::Output;
+}
+
+macro_rules! impl_log10_decades {
+    ($t:ty, $f:ident) => {
+        impl Log10Decades for $t {
+            type Output = $t;
+
+            fn log10_decades(self, reference: Self) -> Self::Output {
+                let ratio = self / reference;
+                #[cfg(feature = "std")]
+                return ratio.log10();
+                #[cfg(not(feature = "std"))]
+                return unsafe { core::intrinsics::$f(ratio) };
+            }
+        }
+    };
+}
+impl_log10_decades!(f32, log10f32);
+impl_log10_decades!(f64, log10f64);
+
+/// `Frexp` is used for implementing an `frexp()` member, which splits a value into a mantissa and
+/// a binary exponent, such that `self == mantissa * 2^exponent`.
+///
+/// For nonzero, finite values the magnitude of the mantissa is in the range `[0.5, 1)`. Zero,
+/// infinities, and `NaN` are returned unchanged as the mantissa, with an exponent of zero. For
+/// quantities, the mantissa keeps the units of the value.
+///
+/// # Example
+/// ```rust
+/// extern crate dimensioned as dim;
+/// use dim::si;
+///
+/// fn main() {
+///     use dim::Frexp;
+///     let (mantissa, exponent) = (12.0 * si::M).frexp();
+///
+///     assert_eq!(mantissa, 0.75 * si::M);
+///     assert_eq!(exponent, 4);
+/// }
+/// ```
+pub trait Frexp: Sized {
+    /// The method for splitting into a mantissa and exponent
+    fn frexp(self) -> (Self, i32);
+}
+
+macro_rules! impl_frexp {
+    ($t:ident, $bits:ident, $mantissa_bits:expr, $exp_mask:expr, $bias:expr, $subnormal_scale:expr) => {
+        impl Frexp for $t {
+            fn frexp(self) -> (Self, i32) {
+                let bits = self.to_bits();
+                let exp = ((bits >> $mantissa_bits) & $exp_mask) as i32;
+                if exp == 0 {
+                    if self == 0.0 {
+                        return (self, 0);
+                    }
+                    // Subnormal, so scale it into the normal range first
+                    let (mantissa, exp) = (self * (1u64 << $subnormal_scale) as $t).frexp();
+                    return (mantissa, exp - $subnormal_scale);
+                }
+                if exp == $exp_mask {
+                    // Infinite or NaN
+                    return (self, 0);
+                }
+                let bits = (bits & !(($exp_mask as $bits) << $mantissa_bits))
+                    | (($bias as $bits) << $mantissa_bits);
+                ($t::from_bits(bits), exp - $bias)
+            }
+        }
+    };
+}
+impl_frexp!(f32, u32, 23, 0xff, 126, 25);
+impl_frexp!(f64, u64, 52, 0x7ff, 1022, 54);
+
 /// `Root` is used for implementing general integer roots for types that aren't necessarily
 /// preserved under root.
 ///
@@ -275,6 +1200,19 @@ fn test_root() {
 ///     assert_eq!(a.sqrt(), x);
 /// }
 /// ```
+///
+/// For quantities, the units are halved, so taking the square root of a quantity whose units are
+/// not all raised to even powers will not compile:
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+///
+/// fn main() {
+///     use dim::si;
+///     use dim::Sqrt;
+///     let x = (4.0 * si::M).sqrt();
+/// }
+/// ```
 pub trait Sqrt {
     /// The resulting type after taking the square root
     type Output;
@@ -303,6 +1241,18 @@ pub trait Sqrt {
 ///     assert_eq!(v.cbrt(), x);
 /// }
 /// ```
+///
+/// As with `Sqrt`, the units must all be raised to powers that are multiples of three:
+///
+/// ```rust,compile_fail
+/// extern crate dimensioned as dim;
+///
+/// fn main() {
+///     use dim::si;
+///     use dim::Cbrt;
+///     let x = (8.0 * si::M2).cbrt();
+/// }
+/// ```
 pub trait Cbrt {
     /// The resulting type after taking the cube root
     type Output;
@@ -341,3 +1291,41 @@ macro_rules! impl_sqcbroot {
 
 impl_sqcbroot!(f32, sqrtf32, core::f32::NAN);
 impl_sqcbroot!(f64, sqrtf64, core::f64::NAN);
+
+/// `Rsqrt` provides an `rsqrt` member function for taking the reciprocal of the square root.
+///
+/// It is implemented for everything that implements `Sqrt` and whose square root implements
+/// `Recip`, so the units of the result are the reciprocal of the square root of the units.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate dimensioned as dim;
+///
+/// fn main() {
+///     use dim::si;
+///     let a = 4.0 * si::M * si::M;
+///
+///     use dim::Rsqrt;
+///     assert_eq!(a.rsqrt(), 0.5 * si::PM);
+///     assert_eq!(4.0.rsqrt(), 0.5);
+/// }
+/// ```
+pub trait Rsqrt {
+    /// The resulting type after taking the reciprocal square root
+    type Output;
+
+    /// The method for taking the reciprocal square root
+    fn rsqrt(self) -> Self::Output;
+}
+
+impl<T> Rsqrt for T
+where
+    T: Sqrt,
+    <T as Sqrt>::Output: Recip,
+{
+    type Output = <<T as Sqrt>::Output as Recip>::Output;
+    fn rsqrt(self) -> Self::Output {
+        self.sqrt().recip()
+    }
+}
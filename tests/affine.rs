@@ -0,0 +1,76 @@
+extern crate dimensioned as dim;
+
+use dim::affine::{Affine, AffineUnit, Celsius, Fahrenheit};
+use dim::si;
+
+fn close(a: si::Kelvin<f64>, b: si::Kelvin<f64>) -> bool {
+    (a - b).value_unsafe.abs() < 1e-9
+}
+
+#[test]
+fn to_and_from_base() {
+    assert_eq!(Affine::<Celsius>::new(0.0).to_base(), 273.15 * si::K);
+    assert_eq!(Affine::<Celsius>::new(-273.15).to_base(), 0.0 * si::K);
+    assert_eq!(
+        Affine::<Celsius>::from_base(373.15 * si::K),
+        Affine::new(100.0)
+    );
+
+    assert!(close(
+        Affine::<Fahrenheit>::new(32.0).to_base(),
+        273.15 * si::K
+    ));
+    assert!(close(
+        Affine::<Fahrenheit>::new(-459.67).to_base(),
+        0.0 * si::K
+    ));
+}
+
+#[test]
+fn convert() {
+    let boiling: Affine<Fahrenheit> = Affine::<Celsius>::new(100.0).convert();
+    assert!((*boiling.value() - 212.0).abs() < 1e-9);
+
+    let cold: Affine<Celsius> = Affine::<Fahrenheit>::new(-40.0).convert();
+    assert!((*cold.value() + 40.0).abs() < 1e-9);
+}
+
+#[test]
+fn differences() {
+    // The offset is not applied to differences
+    assert_eq!(Celsius::difference(10.0), 10.0 * si::K);
+    assert!(close(Fahrenheit::difference(18.0), 10.0 * si::K));
+
+    let t = Affine::<Celsius>::new(15.0);
+    assert_eq!(t + 5.0 * si::K, Affine::new(20.0));
+    assert_eq!(t - 5.0 * si::K, Affine::new(10.0));
+
+    let t = Affine::<Fahrenheit>::new(50.0) + 10.0 * si::K;
+    assert!((*t.value() - 68.0).abs() < 1e-9);
+
+    assert!(Affine::<Celsius>::new(10.0) < Affine::new(20.0));
+}
+
+#[test]
+fn subtract_points() {
+    let hot = Affine::<Celsius>::new(30.0);
+    let cold = Affine::<Celsius>::new(10.0);
+    assert_eq!(hot - cold, 20.0 * si::K);
+    assert_eq!(cold - hot, -20.0 * si::K);
+    assert_eq!(hot - hot, 0.0 * si::K);
+
+    let difference: si::Kelvin<f64> = Affine::<Fahrenheit>::new(212.0) - Affine::new(32.0);
+    assert!(close(difference, 100.0 * si::K));
+
+    // The same as the difference of the absolute temperatures
+    assert!(close(hot - cold, hot.to_base() - cold.to_base()));
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", Affine::<Celsius>::new(21.5)), "21.5 °C");
+    assert_eq!(
+        format!("{:.1}", Affine::<Fahrenheit>::new(98.64)),
+        "98.6 °F"
+    );
+}
@@ -0,0 +1,64 @@
+extern crate dimensioned as dim;
+
+use dim::angle::{self, Degree, Radian};
+use dim::si;
+
+#[test]
+fn conversions() {
+    assert_eq!(
+        (180.0 * angle::DEG).to_radians(),
+        ::std::f64::consts::PI * angle::RAD
+    );
+    assert_eq!(angle::TURN.to_degrees(), 360.0 * angle::DEG);
+    assert_eq!(
+        Degree::new(-45.0f32).to_radians().to_degrees(),
+        Degree::new(-45.0)
+    );
+}
+
+#[test]
+fn trig_in_radians() {
+    assert!((180.0 * angle::DEG).to_radians().sin().value_unsafe.abs() < 1e-12);
+    assert!(
+        ((60.0 * angle::DEG).to_radians().cos() - 0.5)
+            .value_unsafe
+            .abs()
+            < 1e-12
+    );
+    assert!(
+        ((45.0 * angle::DEG).to_radians().tan() - 1.0)
+            .value_unsafe
+            .abs()
+            < 1e-12
+    );
+    assert_eq!(Radian::new(0.0f32).cos(), 1.0);
+}
+
+#[test]
+fn bridge_to_si() {
+    let theta = 0.25 * angle::RAD;
+    let unitless: si::Unitless<f64> = theta.into();
+    assert_eq!(unitless, 0.25 * si::ONE);
+    assert_eq!(
+        Radian::from(30.0 * si::DEG),
+        (30.0 * angle::DEG).to_radians()
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", 1.5 * angle::RAD), "1.5 rad");
+    assert_eq!(format!("{}", 90.0 * angle::DEG), "90 deg");
+}
+
+#[test]
+fn generated_like_other_systems() {
+    assert_eq!(
+        angle::f32consts::TURN,
+        2.0 * ::std::f32::consts::PI * angle::f32consts::RAD
+    );
+    assert_eq!(
+        angle::Angle::<f64, angle::inner::Radian>::new(1.0),
+        angle::RAD
+    );
+}
@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate dimensioned as dim;
+
+use dim::cgs;
+
+#[test]
+fn mechanical_units_fmt() {
+    assert_eq!(format!("{}", 1.0 * cgs::DYN), "1 cm*g*s^-2");
+    assert_eq!(format!("{}", 1.0 * cgs::ERG), "1 cm^2*g*s^-2");
+    assert_eq!(format!("{}", 1.0 * cgs::BA), "1 cm^-1*g*s^-2");
+    assert_eq!(format!("{}", 1.0 * cgs::P), "1 cm^-1*g*s^-1");
+}
+
+#[test]
+fn mechanical_units_dimensions() {
+    use dim::dimensions::{Energy, Force, Pressure};
+
+    fn force<F: Force>(_: F) {}
+    fn energy<E: Energy>(_: E) {}
+    fn pressure<P: Pressure>(_: P) {}
+
+    force(cgs::DYN);
+    energy(cgs::ERG);
+    pressure(cgs::BA);
+
+    dimension_reduces_to!(cgs: Dyne = Gram * Centimeter / Second / Second);
+    dimension_reduces_to!(cgs: Erg = Gram * Centimeter2 / Second2);
+    dimension_reduces_to!(cgs: Barye = Gram / Centimeter / Second2);
+    dimension_reduces_to!(cgs: Poise = Barye * Second);
+}
+
+#[test]
+fn mechanical_units_arithmetic() {
+    let m = 2.0 * cgs::G;
+    let a = 3.0 * cgs::GAL;
+    let f = m * a;
+    assert_eq!(f, 6.0 * cgs::DYN);
+
+    let d = 4.0 * cgs::CM;
+    assert_eq!(f * d, 24.0 * cgs::ERG);
+    assert_eq!(f / (d * d), 0.375 * cgs::BA);
+}
@@ -0,0 +1,63 @@
+extern crate dimensioned as dim;
+
+use dim::{cgs, imperial, mks, si};
+
+#[test]
+fn si_to_cgs_base_units() {
+    assert_eq!(cgs::CGS::from(1.0 * si::M), 100.0 * cgs::CM);
+    assert_eq!(cgs::CGS::from(1.0 * si::KG), 1000.0 * cgs::G);
+    assert_eq!(cgs::CGS::from(1.0 * si::S), 1.0 * cgs::S);
+    assert_eq!(cgs::CGS::from(1.0 * si::ONE), 1.0 * cgs::ONE);
+}
+
+#[test]
+fn si_to_cgs_derived_units() {
+    assert_eq!(cgs::CGS::from(1.0 * si::N), 1e5 * cgs::DYN);
+    assert_eq!(cgs::CGS::from(1.0 * si::J), 1e7 * cgs::ERG);
+    assert_eq!(cgs::CGS::from(2.0 * si::MPS), 200.0 * cgs::CMPS);
+
+    let dyne: cgs::Dyne<f64> = (3.0 * si::N).into();
+    assert_eq!(dyne, 3e5 * cgs::DYN);
+}
+
+#[test]
+fn cgs_mks_round_trip() {
+    let speed = 250.0 * cgs::CMPS;
+    let there = mks::MKS::from(speed);
+    assert_eq!(there, 2.5 * mks::MPS);
+    assert_eq!(cgs::CGS::from(there), speed);
+
+    assert_eq!(mks::MKS::from(1.0 * si::KG), 1.0 * mks::KG);
+    assert_eq!(mks::MKS::from(500.0 * cgs::G), 0.5 * mks::KG);
+}
+
+#[test]
+fn imperial_to_si() {
+    let close = |a: si::Unitless<f64>, b: f64| (a - b).value_unsafe.abs() <= 1e-12 * b.abs();
+
+    assert_eq!(si::SI::from(1.0 * imperial::FT), 0.3048 * si::M);
+    assert_eq!(si::SI::from(1.0 * imperial::LB), 0.453_592_37 * si::KG);
+    assert_eq!(si::SI::from(1.0 * imperial::S), 1.0 * si::S);
+
+    let mile: si::Meter<f64> = (1.0 * imperial::MI).into();
+    assert!(close(mile / (1000.0 * si::M), 1.609_344));
+
+    let force: si::Newton<f64> = (1.0 * imperial::LBF).into();
+    assert!(close(force / si::N, 4.448_221_615_260_5));
+}
+
+#[test]
+fn si_imperial_round_trip() {
+    let close = |a: imperial::Unitless<f64>, b: f64| (a - b).value_unsafe.abs() <= 1e-12 * b.abs();
+
+    let ft: imperial::Foot<f64> = (0.3048 * si::M).into();
+    assert!(close(ft / imperial::FT, 1.0));
+
+    let speed = 88.0 * imperial::FTPS;
+    let back: imperial::FootPerSecond<f64> = si::SI::from(speed).into();
+    assert!(close(back / imperial::FTPS, 88.0));
+    assert!(close(speed / imperial::MPH, 60.0));
+
+    let area: imperial::Foot2<f64> = imperial::Imperial::from(1.0 * si::M * si::M);
+    assert!(close(area / imperial::FT2, 1.0 / (0.3048 * 0.3048)));
+}
@@ -1,3 +1,6 @@
+#![cfg_attr(feature = "oibit", feature(optin_builtin_traits))]
+
+#[macro_use]
 extern crate dimensioned as dim;
 
 use dim::si::{Meter, Second};
@@ -18,3 +21,115 @@ fn derived() {
 
     assert_eq!(d / t, v);
 }
+
+#[test]
+fn computed_units_are_aliases() {
+    use dim::si::{self, Joule, Kilogram, MeterPerSecond, MeterPerSecond2, Newton};
+
+    // The product's units are computed from its factors, and are the same type as the alias, in
+    // whatever order the factors are multiplied
+    fn force(mass: Kilogram<f64>, accel: MeterPerSecond2<f64>) -> Newton<f64> {
+        mass * accel
+    }
+    fn force_reversed(mass: Kilogram<f64>, accel: MeterPerSecond2<f64>) -> Newton<f64> {
+        accel * mass
+    }
+    fn kinetic_energy(mass: Kilogram<f64>, speed: MeterPerSecond<f64>) -> Joule<f64> {
+        0.5 * mass * speed * speed
+    }
+
+    let m = 2.0 * si::KG;
+    let a = 3.0 * si::MPS2;
+    assert_eq!(force(m, a), 6.0 * si::N);
+    assert_eq!(force_reversed(m, a), 6.0 * si::N);
+    assert_eq!(kinetic_energy(m, 4.0 * si::M / si::S), 16.0 * si::N * si::M);
+
+    let f: Newton<f64> = si::KG * si::M / si::S / si::S;
+    assert_eq!(f, si::N);
+}
+
+#[test]
+fn new_with_named_units() {
+    use dim::si::{self, SI};
+    use dim::typenum::Diff;
+
+    let v = 1.5;
+
+    let by_alias = si::MeterPerSecond::new(v);
+    let by_array = SI::<f64, si::inner::MeterPerSecond>::new(v);
+    let by_derived = SI::<f64, Diff<si::inner::Meter, si::inner::Second>>::new(v);
+
+    assert_eq!(by_alias, 1.5 * si::MPS);
+    assert_eq!(by_array, 1.5 * si::MPS);
+    assert_eq!(by_derived, 1.5 * si::MPS);
+}
+
+mod units {
+    make_units! {
+        Units;
+        ONE: Unitless;
+
+        base {
+            M: Meter, "m", Length;
+            KG: Kilogram, "kg", Mass;
+            S: Second, "s", Time;
+        }
+
+        derived {
+            MPS: MeterPerSecond = (Meter / Second), Velocity;
+            N: Newton = (Kilogram * Meter / Second / Second) as "N", Force;
+            J: Joule = (Newton * Meter) as "J";
+            HZ: Hertz = (Unitless / Second) as "Hz";
+        }
+
+        constants {
+            KN: Newton = 1000.0;
+        }
+
+        fmt = true;
+    }
+    pub use self::f64consts::*;
+}
+
+#[test]
+fn derived_print_tokens() {
+    use dim::fmt::UnitFormat;
+
+    let f = 2.0 * units::KG * units::M / units::S / units::S;
+    let f: units::Newton<f64> = f;
+    assert_eq!(f, 2.0 * units::N);
+    assert_eq!(format!("{}", f), "2 N");
+    assert_eq!(format!("{}", units::KN), "1000 N");
+    assert_eq!(format!("{:?}", 3.0 * units::N), "3.0 N");
+    assert_eq!(format!("{:e}", 1500.0 * units::N), "1.5e3 N");
+
+    assert_eq!(format!("{}", 2.0 * units::N * (3.0 * units::M)), "6 J");
+    assert_eq!(format!("{}", 4.0 / units::S), "4 Hz");
+    assert_eq!(format!("{}", UnitFormat::new(4.0 * units::J)), "4 J");
+
+    // Derived units without tokens, and units that match none, print in base units
+    assert_eq!(format!("{}", 3.0 * units::MPS), "3 m*s^-1");
+    assert_eq!(format!("{}", 3.0 * units::N * units::S), "3 m*kg*s^-1");
+    assert_eq!(format!("{}", 3.0 * units::M), "3 m");
+    assert_eq!(format!("{}", 3.0 * units::ONE), "3");
+}
+
+#[test]
+fn value_types() {
+    use units::{f32consts, i64consts};
+
+    let f: units::Newton<f32> = 2.0 * f32consts::KG * f32consts::M / f32consts::S / f32consts::S;
+    assert_eq!(f, 2.0 * f32consts::N);
+    assert_eq!(f32consts::KN, 1000.0 * f32consts::N);
+    assert_eq!(format!("{}", f * (1.5 * f32consts::M)), "3 J");
+    assert_eq!(format!("{}", 0.5f32 / f32consts::S), "0.5 Hz");
+
+    let f: units::Newton<i64> = 2 * i64consts::KG * i64consts::M / i64consts::S / i64consts::S;
+    assert_eq!(f, 2 * i64consts::N);
+    assert_eq!(format!("{}", f * (3 * i64consts::M)), "6 J");
+    assert_eq!(
+        format!("{}", 7 * i64consts::MPS % (2 * i64consts::MPS)),
+        "1 m*s^-1"
+    );
+    assert_eq!(format!("{:x}", 255 * i64consts::M), "ff m");
+}
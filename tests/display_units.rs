@@ -0,0 +1,41 @@
+extern crate dimensioned as dim;
+
+use dim::display_units::LengthUnit;
+use dim::si;
+
+#[test]
+fn length_units() {
+    let x = 1.0 * si::M;
+    let expected = [
+        (LengthUnit::Meter, 1.0, "m"),
+        (LengthUnit::Kilometer, 1e-3, "km"),
+        (LengthUnit::Centimeter, 100.0, "cm"),
+        (LengthUnit::Millimeter, 1e3, "mm"),
+        (LengthUnit::Micrometer, 1e6, "µm"),
+        (LengthUnit::Nanometer, 1e9, "nm"),
+        (LengthUnit::Inch, 39.37007874015748, "in"),
+        (LengthUnit::Foot, 3.280839895013123, "ft"),
+        (LengthUnit::Yard, 1.0936132983377078, "yd"),
+        (LengthUnit::Mile, 6.213711922373339e-4, "mi"),
+    ];
+    assert_eq!(expected.len(), LengthUnit::ALL.len());
+
+    for (&unit, &(expected_unit, value, symbol)) in LengthUnit::ALL.iter().zip(&expected) {
+        assert_eq!(unit, expected_unit);
+        assert!(
+            (unit.value_of(x) - value).abs() <= 1e-12 * value,
+            "{:?}: {} != {}",
+            unit,
+            unit.value_of(x),
+            value
+        );
+        assert_eq!(unit.symbol(), symbol);
+        assert_eq!(format!("{}", unit), symbol);
+
+        let y = unit.length(unit.value_of(x));
+        assert!((y - x).value_unsafe.abs() < 1e-12);
+    }
+
+    assert_eq!(LengthUnit::Mile.value_of(1609.344 * si::M), 1.0);
+    assert_eq!(LengthUnit::Foot.length(2.0), 0.6096 * si::M);
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "std")]
+
+extern crate dimensioned as dim;
+
+use dim::conversion::Nanoseconds;
+use dim::si;
+use std::time::Duration;
+
+#[test]
+fn duration_round_trip() {
+    let durations = [
+        Duration::new(0, 0),
+        Duration::new(0, 1),
+        Duration::new(0, 999_999_999),
+        Duration::new(12, 345_678_901),
+        Duration::new(86_400, 1),
+        Duration::new(8_000_000, 999_999_999),
+    ];
+
+    for &d in &durations {
+        let t = si::Second::from(d);
+        assert_eq!(t.to_duration(), Some(d));
+    }
+}
+
+#[test]
+fn duration_from_second() {
+    assert_eq!(si::Second::from(Duration::new(2, 5)), 2.000_000_005 * si::S);
+    assert_eq!((0.5 * si::S).to_duration(), Some(Duration::from_millis(500)));
+    assert_eq!((1e-9 * si::S).to_duration(), Some(Duration::new(0, 1)));
+    assert_eq!((0.999_999_999_9 * si::S).to_duration(), Some(Duration::new(1, 0)));
+}
+
+#[test]
+fn duration_invalid() {
+    assert_eq!((-1e-9 * si::S).to_duration(), None);
+    assert_eq!((::std::f64::NAN * si::S).to_duration(), None);
+    assert_eq!((::std::f64::INFINITY * si::S).to_duration(), None);
+    assert_eq!((1e30 * si::S).to_duration(), None);
+}
+
+#[test]
+fn multiply_by_duration() {
+    let v = 3.0 * si::MPS;
+    let d = Duration::new(2, 500_000_000);
+
+    let x: si::Meter<f64> = v * d;
+    assert_eq!(x, 7.5 * si::M);
+    assert_eq!(d * v, 7.5 * si::M);
+
+    let rate = 4.0 * si::HZ;
+    let events: si::Unitless<f64> = rate * Duration::from_millis(250);
+    assert_eq!(events, 1.0);
+
+    let t: si::Second2<f64> = Duration::new(3, 0) * si::S;
+    assert_eq!(t, 3.0 * si::S2);
+}
+
+#[test]
+fn integer_seconds_duration() {
+    use dim::si::i64consts::S;
+
+    assert_eq!((5 * S).to_duration(), Some(Duration::new(5, 0)));
+    assert_eq!((-5 * S).to_duration(), None);
+    assert_eq!(si::Second::from_duration(Duration::new(5, 0)), Some(5 * S));
+    assert_eq!(
+        si::Second::from_duration(Duration::new(5, 999_999_999)),
+        Some(5 * S)
+    );
+    assert_eq!(
+        si::Second::from_duration(Duration::new(u64::max_value(), 0)),
+        None
+    );
+
+    // Integer and float seconds agree
+    let d = Duration::new(86_400, 0);
+    assert_eq!(
+        si::Second::from_duration(d).unwrap().to_duration(),
+        si::Second::from(d).to_duration()
+    );
+}
+
+#[test]
+fn nanos_round_trip() {
+    let max = i64::max_value();
+    let nanos = [
+        0,
+        1,
+        999_999_999,
+        1_000_000_000,
+        max - 1_000_000_000,
+        max - 1,
+        max,
+    ];
+
+    for &n in &nanos {
+        let t = Nanoseconds(n);
+        let d = t.to_duration().unwrap();
+        assert_eq!(Nanoseconds::from_duration(d), Some(t));
+    }
+
+    // Every nanosecond near the top of the range is kept
+    for n in (max - 1000)..max {
+        let d = Nanoseconds(n).to_duration().unwrap();
+        let next = Nanoseconds(n + 1).to_duration().unwrap();
+        assert_eq!(next - d, Duration::new(0, 1));
+        assert_eq!(Nanoseconds::from_duration(d), Some(Nanoseconds(n)));
+    }
+}
+
+#[test]
+fn nanos_duration() {
+    let max = i64::max_value();
+    let d = Nanoseconds(max).to_duration().unwrap();
+    assert_eq!(
+        d,
+        Duration::new((max / 1_000_000_000) as u64, (max % 1_000_000_000) as u32)
+    );
+    assert_eq!(Nanoseconds::from_duration(d + Duration::new(0, 1)), None);
+    assert_eq!(Nanoseconds(-1).to_duration(), None);
+    assert_eq!(Nanoseconds(12) + Nanoseconds(30), Nanoseconds(42));
+    assert_eq!(Nanoseconds(12) - Nanoseconds(30), Nanoseconds(-18));
+}
+
+#[test]
+fn nanos_seconds() {
+    use dim::si::i64consts::S;
+
+    assert_eq!(Nanoseconds(1_500_000_000).to_seconds(), 1.5 * si::S);
+    assert_eq!(Nanoseconds(-250_000_000).to_seconds(), -0.25 * si::S);
+    assert_eq!(
+        Nanoseconds::from_seconds(3 * S),
+        Some(Nanoseconds(3_000_000_000))
+    );
+    assert_eq!(Nanoseconds::from_seconds(i64::max_value() * S), None);
+    assert_eq!(format!("{}", Nanoseconds(7)), "7 ns");
+}
+
+#[cfg(feature = "try_from")]
+#[test]
+fn nanos_try_from() {
+    use dim::conversion::DurationRangeError;
+    use dim::si::i64consts::S;
+    use std::convert::TryFrom;
+
+    let t = Nanoseconds(3_000_000_007);
+    assert_eq!(Duration::try_from(t), Ok(Duration::new(3, 7)));
+    assert_eq!(Nanoseconds::try_from(Duration::new(3, 7)), Ok(t));
+    assert_eq!(Duration::try_from(Nanoseconds(-5)), Err(DurationRangeError));
+    assert_eq!(
+        Nanoseconds::try_from(Duration::new(u64::max_value(), 0)),
+        Err(DurationRangeError)
+    );
+    assert_eq!(Duration::try_from(3 * S), Ok(Duration::new(3, 0)));
+    assert_eq!(Duration::try_from(-3 * S), Err(DurationRangeError));
+}
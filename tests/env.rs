@@ -0,0 +1,87 @@
+#![cfg(feature = "std")]
+
+extern crate dimensioned as dim;
+
+use dim::env::{from_env, FromEnvError};
+use dim::si;
+use std::env;
+
+#[test]
+fn from_env_set() {
+    env::set_var("DIMENSIONED_TEST_TRACK_LENGTH", "1.5e3");
+    let length: si::Meter<f64> = from_env("DIMENSIONED_TEST_TRACK_LENGTH").unwrap();
+    assert_eq!(length, 1500.0 * si::M);
+
+    env::set_var("DIMENSIONED_TEST_TIMEOUT", " 30 ");
+    let timeout = from_env::<si::Second<f64>>("DIMENSIONED_TEST_TIMEOUT").unwrap();
+    assert_eq!(timeout, 30.0 * si::S);
+}
+
+#[test]
+fn from_env_errors() {
+    env::remove_var("DIMENSIONED_TEST_MISSING");
+    assert_eq!(
+        from_env::<si::Meter<f64>>("DIMENSIONED_TEST_MISSING"),
+        Err(FromEnvError::NotPresent)
+    );
+
+    env::set_var("DIMENSIONED_TEST_INVALID", "12 m");
+    let err = from_env::<si::Meter<f64>>("DIMENSIONED_TEST_INVALID").unwrap_err();
+    match err {
+        FromEnvError::Invalid(_) => (),
+        _ => panic!("unexpected error: {:?}", err),
+    }
+    assert_eq!(
+        err.to_string(),
+        "environment variable was not a valid number: invalid float literal"
+    );
+}
+
+#[test]
+fn from_env_with_units_set() {
+    use dim::env::from_env_with_units;
+
+    env::set_var("DIMENSIONED_TEST_ACCELERATION", " 9.81 m*s^-2 ");
+    let g: si::MeterPerSecond2<f64> = from_env_with_units("DIMENSIONED_TEST_ACCELERATION").unwrap();
+    assert_eq!(g, 9.81 * si::MPS2);
+
+    env::set_var("DIMENSIONED_TEST_FORCE", "2 N");
+    assert_eq!(
+        from_env_with_units::<si::Newton<f64>>("DIMENSIONED_TEST_FORCE"),
+        Ok(2.0 * si::N)
+    );
+
+    env::set_var("DIMENSIONED_TEST_GAIN", "0.5");
+    assert_eq!(
+        from_env_with_units::<si::Unitless<f64>>("DIMENSIONED_TEST_GAIN"),
+        Ok(0.5 * si::ONE)
+    );
+}
+
+#[test]
+fn from_env_with_units_errors() {
+    use dim::env::from_env_with_units;
+
+    env::set_var("DIMENSIONED_TEST_WRONG_UNITS", "12 s");
+    let err = from_env_with_units::<si::Meter<f64>>("DIMENSIONED_TEST_WRONG_UNITS").unwrap_err();
+    assert_eq!(err, FromEnvError::Units);
+    assert_eq!(err.to_string(), "environment variable had unexpected units");
+
+    env::set_var("DIMENSIONED_TEST_NO_UNITS", "12");
+    assert_eq!(
+        from_env_with_units::<si::Meter<f64>>("DIMENSIONED_TEST_NO_UNITS"),
+        Err(FromEnvError::Units)
+    );
+
+    env::set_var("DIMENSIONED_TEST_BAD_VALUE", "twelve m");
+    match from_env_with_units::<si::Meter<f64>>("DIMENSIONED_TEST_BAD_VALUE") {
+        Err(FromEnvError::Invalid(_)) => (),
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    env::remove_var("DIMENSIONED_TEST_MISSING_UNITS");
+    assert_eq!(
+        from_env_with_units::<si::Meter<f64>>("DIMENSIONED_TEST_MISSING_UNITS"),
+        Err(FromEnvError::NotPresent)
+    );
+}
@@ -0,0 +1,48 @@
+extern crate dimensioned as dim;
+
+use dim::fixed::{FixedOutOfRange, FromFixed, ToFixed};
+use dim::si;
+
+#[test]
+fn fixed_round_trip() {
+    let lengths = [0.0, 1.0, -1.0, 0.125, 12.345_678, -98_765.432_1];
+
+    for &l in &lengths {
+        let x = l * si::M;
+        let fixed = x.to_fixed(6).unwrap();
+        assert_eq!(fixed.from_fixed(6), x);
+        assert_eq!(fixed.from_fixed(6).to_fixed(6), Ok(fixed));
+    }
+}
+
+#[test]
+fn fixed_scales() {
+    let x = 1234.5678 * si::S;
+
+    assert_eq!(x.to_fixed(0), Ok(si::Second::new(1235)));
+    assert_eq!(x.to_fixed(2), Ok(si::Second::new(123_457)));
+    assert_eq!(x.to_fixed(-2), Ok(si::Second::new(12)));
+
+    assert_eq!(si::Second::new(123_457).from_fixed(2), 1234.57 * si::S);
+    assert_eq!(si::Second::new(12).from_fixed(-2), 1200.0 * si::S);
+}
+
+#[test]
+fn fixed_out_of_range() {
+    assert_eq!((1e16 * si::M).to_fixed(3), Err(FixedOutOfRange));
+    assert_eq!((-1e16 * si::M).to_fixed(3), Err(FixedOutOfRange));
+    assert_eq!((::std::f64::NAN * si::M).to_fixed(0), Err(FixedOutOfRange));
+    assert_eq!(
+        (::std::f64::INFINITY * si::M).to_fixed(0),
+        Err(FixedOutOfRange)
+    );
+
+    assert_eq!(
+        (-9_223_372_036_854_775_808.0 * si::M).to_fixed(0),
+        Ok(si::Meter::new(::std::i64::MIN))
+    );
+    assert_eq!(
+        (9_223_372_036_854_775_808.0 * si::M).to_fixed(0),
+        Err(FixedOutOfRange)
+    );
+}
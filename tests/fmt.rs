@@ -0,0 +1,382 @@
+extern crate dimensioned as dim;
+
+use dim::si;
+
+#[cfg(feature = "std")]
+#[test]
+fn sig_figs() {
+    use dim::SigFigs;
+
+    assert_eq!(format!("{}", (1.23456 * si::M).sig_figs(3)), "1.23 m");
+    assert_eq!(format!("{}", (12345.0 * si::M).sig_figs(3)), "12300 m");
+    assert_eq!(
+        format!("{}", (-0.000987654 * si::M).sig_figs(2)),
+        "-0.00099 m"
+    );
+    assert_eq!(format!("{}", (999.6 * si::M).sig_figs(3)), "1000 m");
+    assert_eq!(format!("{}", (1.5 * si::M).sig_figs(0)), "2 m");
+    assert_eq!(format!("{}", (0.0 * si::M).sig_figs(3)), "0 m");
+
+    assert_eq!(
+        (6.62607015e-34 * si::J * si::S).sig_figs(3),
+        6.63e-34 * si::J * si::S
+    );
+    assert_eq!((1.23456e-310 * si::M).sig_figs(3), 1.23e-310 * si::M);
+    assert_eq!((6.0221e23 * si::MOL).sig_figs(2), 6.0e23 * si::MOL);
+    assert_eq!(
+        si::Meter::new(1.23456f32).sig_figs(4),
+        si::Meter::new(1.235f32)
+    );
+
+    assert!((::std::f64::NAN * si::M).sig_figs(3).value_unsafe.is_nan());
+    assert_eq!(
+        (::std::f64::INFINITY * si::M).sig_figs(3),
+        ::std::f64::INFINITY * si::M
+    );
+}
+
+#[test]
+fn frexp() {
+    use dim::Frexp;
+
+    // Split the power of two so that neither half over- or underflows for subnormals
+    fn reconstruct(mantissa: f64, exponent: i32) -> f64 {
+        mantissa * 2f64.powi(exponent / 2) * 2f64.powi(exponent - exponent / 2)
+    }
+
+    for &x in &[
+        12.0,
+        -0.3,
+        1.0,
+        0.5,
+        6.02e23,
+        1.6e-19,
+        5e-324,
+        2.2e-308,
+        ::std::f64::MAX,
+    ] {
+        let (mantissa, exponent) = (x * si::C).frexp();
+        let m = mantissa.value_unsafe;
+        assert!(0.5 <= m.abs() && m.abs() < 1.0, "mantissa {} of {}", m, x);
+        assert_eq!(reconstruct(m, exponent), x);
+    }
+
+    let (mantissa, exponent) = si::Meter::new(-40.0f32).frexp();
+    assert_eq!(mantissa, si::Meter::new(-0.625f32));
+    assert_eq!(exponent, 6);
+    assert_eq!(si::Meter::new(1.0e-40f32).frexp().1, -132);
+
+    assert_eq!((0.0 * si::M).frexp(), (0.0 * si::M, 0));
+    assert_eq!(
+        (::std::f64::NEG_INFINITY * si::M).frexp(),
+        (::std::f64::NEG_INFINITY * si::M, 0)
+    );
+    assert!((::std::f64::NAN * si::M).frexp().0.value_unsafe.is_nan());
+}
+
+#[test]
+fn log10_decades() {
+    use dim::Log10Decades;
+
+    let reference = 20e-6 * si::PA;
+
+    assert_eq!((20e-6 * si::PA).log10_decades(reference), 0.0);
+    assert!(((200e-6 * si::PA).log10_decades(reference) - 1.0).abs() < 1e-12);
+    assert!(((2e-3 * si::PA).log10_decades(reference) - 2.0).abs() < 1e-12);
+    assert!(((2e-6 * si::PA).log10_decades(reference) + 1.0).abs() < 1e-12);
+
+    assert_eq!(
+        si::Meter::new(1000.0f32).log10_decades(si::Meter::new(10.0f32)),
+        2.0f32
+    );
+    assert!((-1.0 * si::PA).log10_decades(reference).is_nan());
+}
+
+#[test]
+fn negative_zero() {
+    use dim::cgs;
+    use dim::fmt::UnitFormat;
+
+    assert_eq!(format!("{}", -0.0 * si::M), "0 m");
+    assert_eq!(format!("{:.2}", -0.0 * si::M), "0.00 m");
+    assert_eq!(format!("{:.1}", -0.01 * si::M), "0.0 m");
+    assert_eq!(format!("{:>5}", -0.0 * si::M), "    0 m");
+    assert_eq!(format!("{:+}", -0.0 * si::M), "+0 m");
+    assert_eq!(format!("{}", -0.0 * cgs::CM), "0 cm");
+    assert_eq!(format!("{}", UnitFormat::new(-0.0 * si::M)), "0 m");
+
+    assert_eq!(format!("{}", -0.5 * si::M), "-0.5 m");
+    assert_eq!(format!("{:.1}", -0.05 * si::M), "-0.1 m");
+    assert_eq!(format!("{}", -10.0 * si::M), "-10 m");
+    assert_eq!(format!("{}", ::std::f64::NEG_INFINITY * si::M), "-inf m");
+    assert_eq!(format!("{:?}", -0.0 * si::M), "-0.0 m");
+}
+
+#[test]
+fn exponent_notation() {
+    use dim::cgs;
+
+    assert_eq!(format!("{:e}", 1500.0 * si::M), "1.5e3 m");
+    assert_eq!(format!("{:E}", 1500.0 * si::M), "1.5E3 m");
+    assert_eq!(format!("{:.2e}", 0.00125 * si::S), "1.25e-3 s");
+    assert_eq!(format!("{:e}", 2.0e6 * si::MPS), "2e6 m*s^-1");
+    assert_eq!(format!("{:E}", 1500.0 * si::J), "1.5E3 J");
+    assert_eq!(format!("{:e}", 250.0 * cgs::CM), "2.5e2 cm");
+}
+
+#[test]
+fn alternate_superscripts() {
+    let x = 2.0 * si::JS;
+    assert_eq!(format!("{}", x), "2 m^2*kg*s^-1");
+    assert_eq!(format!("{:#}", x), "2 m²*kg*s⁻¹");
+    assert_eq!(format!("{:#}", 1.5 * si::KGPM3), "1.5 m⁻³*kg");
+    assert_eq!(format!("{:#}", 3.0 * si::M), "3 m");
+    assert_eq!(format!("{:#}", 3.0 * si::J), "3 J");
+
+    let m6 = si::M2 * si::M2 * si::M2;
+    assert_eq!(format!("{:#}", m6 * m6), "1 m¹²");
+    assert_eq!(format!("{:#}", 1.0 / (m6 * m6)), "1 m⁻¹²");
+    assert_eq!(format!("{:#x}", 255 * si::Meter2::new(1)), "0xff m²");
+}
+
+#[test]
+fn alternate_superscripts_cgs() {
+    use dim::{cgs, mks};
+
+    let e = 1.5 * cgs::STATC;
+    assert_eq!(format!("{}", e), "1.5 cm^1.5*g^0.5*s^-1");
+    assert_eq!(format!("{:#}", e), "1.5 cm³ᐟ²*g¹ᐟ²*s⁻¹");
+    assert_eq!(format!("{:#}", 1.0 / e), "0.6666666666666666 cm⁻³ᐟ²*g⁻¹ᐟ²*s");
+    assert_eq!(format!("{:#}", 2.0 * cgs::CM * cgs::CM), "2 cm²");
+    assert_eq!(format!("{:#}", 2.0 * cgs::CM), "2 cm");
+    assert_eq!(format!("{:#}", 1.0 * mks::M / (mks::S * mks::S)), "1 m*s⁻²");
+}
+
+#[test]
+fn unit_format_matches_display() {
+    use dim::cgs;
+    use dim::fmt::UnitFormat;
+
+    assert_eq!(format!("{}", UnitFormat::new(3.0 * si::J)), "3 J");
+    assert_eq!(
+        format!("{}", UnitFormat::new(3.0 * si::JS)),
+        "3 m^2*kg*s^-1"
+    );
+    assert_eq!(
+        format!("{}", UnitFormat::new(2.0 * si::HZ)),
+        format!("{}", 2.0 * si::HZ)
+    );
+    assert_eq!(format!("{}", UnitFormat::new(1.0 * si::ONE)), "1");
+
+    let e = 1.5 * cgs::STATC;
+    assert_eq!(format!("{}", UnitFormat::new(e)), format!("{}", e));
+}
+
+#[test]
+fn unit_format_fraction() {
+    use dim::fmt::{Grouping, UnitFormat};
+
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(2.0 * si::MPS).grouping(Grouping::Fraction)
+        ),
+        "2 m/s"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(2.0 * si::M).grouping(Grouping::Fraction)
+        ),
+        "2 m"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(2.0 * si::KGPM3 / si::S2).grouping(Grouping::Fraction)
+        ),
+        "2 kg/(m^3*s^2)"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(2.0 * si::HZ).grouping(Grouping::Fraction)
+        ),
+        "2 1/s"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(2.0 * si::HZ)
+                .grouping(Grouping::Fraction)
+                .reciprocal_one(false)
+        ),
+        "2 s^-1"
+    );
+    assert_eq!(
+        format!(
+            "{:.2}",
+            UnitFormat::new(2.0 * si::MPS).grouping(Grouping::Fraction)
+        ),
+        "2.00 m/s"
+    );
+}
+
+#[test]
+fn unit_format_styles() {
+    use dim::cgs;
+    use dim::fmt::{ExponentStyle, Grouping, UnitFormat};
+
+    let x = 1.0 * si::KGPM3 / si::S2;
+    assert_eq!(
+        format!("{}", UnitFormat::new(x).exponents(ExponentStyle::Unicode)),
+        "1 m⁻³*kg*s⁻²"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(x)
+                .grouping(Grouping::Fraction)
+                .separator(" \\cdot ")
+                .exponents(ExponentStyle::Latex)
+        ),
+        "1 kg/(m^{3} \\cdot s^{2})"
+    );
+    assert_eq!(
+        format!("{}", UnitFormat::new(x).exponents(ExponentStyle::Latex)),
+        "1 m^{-3}*kg*s^{-2}"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(1.0 * si::M2 * si::M2 * si::M2 * si::M2 * si::M2)
+                .exponents(ExponentStyle::Unicode)
+        ),
+        "1 m¹⁰"
+    );
+
+    // Charge in CGS has half-integer exponents
+    let q = UnitFormat::new(1.0 * cgs::STATC);
+    assert_eq!(format!("{}", q), "1 cm^1.5*g^0.5*s^-1");
+    assert_eq!(
+        format!("{}", q.exponents(ExponentStyle::Unicode)),
+        "1 cm³ᐟ²*g¹ᐟ²*s⁻¹"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            q.exponents(ExponentStyle::Latex)
+                .grouping(Grouping::Fraction)
+        ),
+        "1 cm^{3/2}*g^{1/2}/s"
+    );
+}
+
+#[test]
+fn unit_format_thousands() {
+    use dim::fmt::UnitFormat;
+
+    let grouped = |x: si::Meter<f64>, sep| format!("{}", UnitFormat::new(x).thousands(sep));
+
+    assert_eq!(grouped(1_500_000.0 * si::M, ","), "1,500,000 m");
+    assert_eq!(grouped(-1_500_000.0 * si::M, ","), "-1,500,000 m");
+    assert_eq!(grouped(999.0 * si::M, ","), "999 m");
+    assert_eq!(grouped(1000.0 * si::M, ","), "1,000 m");
+    assert_eq!(grouped(12_345.678 * si::M, " "), "12 345.678 m");
+    assert_eq!(grouped(-0.5 * si::M, ","), "-0.5 m");
+    assert_eq!(grouped(1e9 * si::M, "_"), "1_000_000_000 m");
+    assert_eq!(grouped(::std::f64::INFINITY * si::M, ","), "inf m");
+
+    assert_eq!(
+        format!(
+            "{:.2}",
+            UnitFormat::new(1_234_567.891 * si::M).thousands(".")
+        ),
+        "1.234.567.89 m"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(si::Meter::new(-12_345i32)).thousands(",")
+        ),
+        "-12,345 m"
+    );
+}
+
+#[test]
+fn parse() {
+    use dim::cgs;
+    use dim::fmt::ParseQuantityError;
+
+    assert_eq!("5 m".parse(), Ok(5.0 * si::M));
+    assert_eq!("  -2.5e3   m  ".parse(), Ok(-2500.0 * si::M));
+    assert_eq!("9.81 m*s^-2".parse(), Ok(9.81 * si::MPS2));
+    assert_eq!("2 N".parse(), Ok(2.0 * si::N));
+    assert_eq!("5 m*kg*s^-2".parse(), Ok(5.0 * si::N));
+    assert_eq!("5 kg*m*s^-2".parse(), Ok(5.0 * si::N));
+    assert_eq!("5 s^-2*m*kg".parse(), Ok(5.0 * si::N));
+    assert_eq!("4 A*s".parse(), Ok(4.0 * si::C));
+    assert_eq!("2 s*m".parse(), Ok(2.0 * si::M * si::S));
+    assert_eq!("1.5 g^0.5*cm^1.5*s^-1".parse(), Ok(1.5 * cgs::STATC));
+    assert_eq!("3".parse(), Ok(3.0 * si::ONE));
+    assert_eq!("7 m".parse(), Ok(si::Meter::new(7i32)));
+    let e = 1.5 * cgs::STATC;
+    assert_eq!(format!("{}", e).parse(), Ok(e));
+
+    assert_eq!(
+        "5 s".parse::<si::Meter<f64>>(),
+        Err(ParseQuantityError::Units)
+    );
+    assert_eq!(
+        "5".parse::<si::Meter<f64>>(),
+        Err(ParseQuantityError::Units)
+    );
+    assert_eq!(
+        "5 m*s".parse::<si::Meter<f64>>(),
+        Err(ParseQuantityError::Units)
+    );
+    assert_eq!(
+        "5 m".parse::<si::Unitless<f64>>(),
+        Err(ParseQuantityError::Units)
+    );
+    assert_eq!(
+        "5 m*kg*s".parse::<si::Newton<f64>>(),
+        Err(ParseQuantityError::Units)
+    );
+    assert_eq!(
+        "5 kg*m*s^-2*s".parse::<si::Newton<f64>>(),
+        Err(ParseQuantityError::Units)
+    );
+    assert!(match "five m".parse::<si::Meter<f64>>() {
+        Err(ParseQuantityError::Value(_)) => true,
+        _ => false,
+    });
+    assert!(match "2.5 m".parse::<si::Meter<i32>>() {
+        Err(ParseQuantityError::Value(_)) => true,
+        _ => false,
+    });
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn unit_pair() {
+    use dim::cgs;
+    use dim::fmt::UnitPair;
+
+    let quantities = [1.5 * si::N, -2.0 * si::N, 0.0 * si::N];
+    for &q in &quantities {
+        let (value, units) = q.to_pair();
+        assert_eq!(units, "N");
+        assert_eq!(si::Newton::from_pair((value, &units)), Some(q));
+    }
+    assert_eq!(si::Newton::from_pair((3.0, "kg*m*s^-2")), Some(3.0 * si::N));
+    assert_eq!(si::Newton::<f64>::from_pair((3.0, "m")), None);
+
+    assert_eq!((4.0 * si::MPS).to_pair(), (4.0, "m*s^-1".to_string()));
+    assert_eq!((4.0 * si::ONE).to_pair(), (4.0, String::new()));
+    assert_eq!(si::Unitless::from_pair((4.0, "")), Some(4.0 * si::ONE));
+
+    let e = 1.5 * cgs::STATC;
+    let (value, units) = e.to_pair();
+    assert_eq!(cgs::CGS::from_pair((value, &units)), Some(e));
+}
@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate dimensioned as dim;
+
+use dim::imperial;
+
+#[test]
+fn length_and_mass_ratios() {
+    assert_eq!(imperial::FT / imperial::IN, 12.0);
+    assert_eq!(imperial::YD / imperial::FT, 3.0);
+    assert_eq!(imperial::MI / imperial::YD, 1760.0);
+    assert_eq!(imperial::LB / imperial::OZ, 16.0);
+    assert_eq!(imperial::ST / imperial::LB, 14.0);
+}
+
+#[test]
+fn units_fmt() {
+    assert_eq!(format!("{}", 6.0 * imperial::IN), "0.5 ft");
+    assert_eq!(format!("{}", 2.0 * imperial::FTPS2), "2 ft*s^-2");
+    assert_eq!(format!("{}", 3.0 * imperial::PDL), "3 pdl");
+    assert_eq!(format!("{}", 1.0 * imperial::FT * imperial::FT), "1 ft^2");
+}
+
+#[test]
+fn units_dimensions() {
+    use dim::dimensions::{Acceleration, Force, Velocity};
+
+    fn velocity<V: Velocity>(_: V) {}
+    fn acceleration<A: Acceleration>(_: A) {}
+    fn force<F: Force>(_: F) {}
+
+    velocity(imperial::MPH);
+    acceleration(imperial::G0);
+    force(imperial::LBF);
+
+    dimension_reduces_to!(imperial: Poundal = Pound * Foot / Second2);
+    dimension_reduces_to!(imperial: FootPerSecond = Foot / Second);
+}
@@ -0,0 +1,327 @@
+extern crate dimensioned as dim;
+
+use dim::si;
+
+#[test]
+fn horner_dimensionless() {
+    use dim::numeric::{horner, Horner};
+
+    let one = si::ONE;
+    let x = 0.5 * one;
+
+    // 2 - 4x + 8x^3
+    let coefficients = [2.0 * one, -4.0 * one, 0.0 * one, 8.0 * one];
+    assert_eq!(horner(x, &coefficients), 1.0 * one);
+    assert_eq!(
+        (2.0 * one, -4.0 * one, 0.0 * one, 8.0 * one).horner(x),
+        1.0 * one
+    );
+
+    assert_eq!(horner(x, &[]), 0.0 * one);
+    assert_eq!(horner(x, &[3.0 * one]), 3.0 * one);
+}
+
+#[test]
+fn horner_dimensioned() {
+    use dim::numeric::Horner;
+
+    // Energy stored in a spring as a function of its extension
+    let k = 10.0 * si::N / si::M;
+    let x = 2.0 * si::M;
+    let energy = (0.0 * si::J, 0.0 * si::N, 0.5 * k).horner(x);
+
+    assert_eq!(energy, 20.0 * si::J);
+}
+
+#[test]
+fn dot_slices() {
+    use dim::numeric::dot_slices;
+
+    let forces = [10.0 * si::N, -2.0 * si::N, 0.5 * si::N];
+    let displacements = [0.5 * si::M, 3.0 * si::M, 4.0 * si::M];
+    assert_eq!(dot_slices(&forces, &displacements), 1.0 * si::J);
+
+    let none: [si::Newton<f64>; 0] = [];
+    let nowhere: [si::Meter<f64>; 0] = [];
+    assert_eq!(dot_slices(&none, &nowhere), 0.0 * si::J);
+}
+
+#[test]
+#[should_panic]
+fn dot_slices_unequal_lengths() {
+    use dim::numeric::dot_slices;
+
+    dot_slices(&[1.0 * si::N], &[1.0 * si::M, 2.0 * si::M]);
+}
+
+#[test]
+fn kahan_sum() {
+    use dim::numeric::KahanSum;
+
+    // One large value followed by many small ones, each too small to change the naive total
+    let step = 1e-16 * si::KG;
+    let mut naive = 1.0 * si::KG;
+    let mut sum = KahanSum::new();
+    sum.add(1.0 * si::KG);
+    for _ in 0..1_000 {
+        naive += step;
+        sum.add(step);
+    }
+
+    let exact = 1.0000000000001 * si::KG;
+    assert_eq!(naive, 1.0 * si::KG);
+    assert!((sum.total() - exact).value_unsafe.abs() <= ::std::f64::EPSILON);
+
+    let empty: KahanSum<si::Kilogram<f64>> = KahanSum::default();
+    assert_eq!(empty.total(), 0.0 * si::KG);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn scale_and_offset_all() {
+    use dim::numeric::{offset_all, scale_all};
+
+    let readings = [0.0 * si::K, 10.0 * si::K, -4.0 * si::K];
+
+    assert_eq!(
+        scale_all(&readings, 0.5),
+        vec![0.0 * si::K, 5.0 * si::K, -2.0 * si::K]
+    );
+    assert_eq!(
+        offset_all(&readings, 273.0 * si::K),
+        vec![273.0 * si::K, 283.0 * si::K, 269.0 * si::K]
+    );
+    assert_eq!(
+        offset_all(&scale_all(&readings, 2.0), -1.0 * si::K),
+        vec![-1.0 * si::K, 19.0 * si::K, -9.0 * si::K]
+    );
+    assert!(scale_all::<si::Kelvin<f64>>(&[], 2.0).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cumsum() {
+    use dim::numeric::cumsum;
+
+    let flows = [2.0 * si::MOL, 0.5 * si::MOL, -1.0 * si::MOL, 4.0 * si::MOL];
+    assert_eq!(
+        cumsum(&flows),
+        vec![2.0 * si::MOL, 2.5 * si::MOL, 1.5 * si::MOL, 5.5 * si::MOL]
+    );
+    assert_eq!(cumsum(&flows[..1]), vec![2.0 * si::MOL]);
+    assert!(cumsum::<si::Mole<f64>>(&[]).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cumulative_integrate() {
+    use dim::numeric::cumulative_integrate;
+
+    // A constant velocity gives evenly spaced positions
+    let velocities = [3.0 * si::MPS; 4];
+    let positions: Vec<si::Meter<f64>> = cumulative_integrate(&velocities, 2.0 * si::S);
+    assert_eq!(
+        positions,
+        vec![6.0 * si::M, 12.0 * si::M, 18.0 * si::M, 24.0 * si::M]
+    );
+
+    let currents = [1.0 * si::A, -0.5 * si::A];
+    assert_eq!(
+        cumulative_integrate(&currents, 4.0 * si::S),
+        vec![4.0 * si::C, 2.0 * si::C]
+    );
+    assert!(cumulative_integrate::<si::MeterPerSecond<f64>, _>(&[], si::S).is_empty());
+}
+
+#[test]
+fn clamp() {
+    use dim::numeric;
+    use dim::Dimensioned;
+
+    // Dimension-agnostic bounding, written once
+    fn limit<Q: Dimensioned + PartialOrd + Copy>(xs: &[Q], lo: Q, hi: Q) -> Vec<Q> {
+        xs.iter().map(|&x| numeric::clamp(x, lo, hi)).collect()
+    }
+
+    let lengths = [-1.0 * si::M, 0.5 * si::M, 3.0 * si::M];
+    assert_eq!(
+        limit(&lengths, 0.0 * si::M, 2.0 * si::M),
+        vec![0.0 * si::M, 0.5 * si::M, 2.0 * si::M]
+    );
+
+    let times = [si::Second::new(-5), si::Second::new(7), si::Second::new(11)];
+    assert_eq!(
+        limit(&times, si::Second::new(0), si::Second::new(10)),
+        vec![si::Second::new(0), si::Second::new(7), si::Second::new(10)]
+    );
+
+    assert_eq!(
+        numeric::clamp(1.0 * si::S, 1.0 * si::S, 1.0 * si::S),
+        1.0 * si::S
+    );
+    assert!(
+        numeric::clamp(::std::f64::NAN * si::S, 0.0 * si::S, 1.0 * si::S)
+            .value_unsafe
+            .is_nan()
+    );
+}
+
+#[test]
+#[should_panic]
+fn clamp_inverted_bounds() {
+    dim::numeric::clamp(1.0 * si::M, 2.0 * si::M, 0.0 * si::M);
+}
+
+#[test]
+fn sample_keyframes() {
+    use dim::numeric::sample_keyframes;
+
+    let frames = [
+        (1.0 * si::S, 10.0 * si::K),
+        (3.0 * si::S, 20.0 * si::K),
+        (4.0 * si::S, 0.1 * si::K),
+    ];
+
+    // Between keyframes
+    assert_eq!(sample_keyframes(&frames, 2.0 * si::S), Some(15.0 * si::K));
+    assert_eq!(sample_keyframes(&frames, 1.5 * si::S), Some(12.5 * si::K));
+    assert_eq!(sample_keyframes(&frames, 3.5 * si::S), Some(10.05 * si::K));
+
+    // At keyframes
+    for &(t, v) in &frames {
+        assert_eq!(sample_keyframes(&frames, t), Some(v));
+    }
+
+    // Outside the range
+    assert_eq!(sample_keyframes(&frames, 0.5 * si::S), None);
+    assert_eq!(sample_keyframes(&frames, 4.5 * si::S), None);
+    assert_eq!(sample_keyframes(&frames, ::std::f64::NAN * si::S), None);
+    assert_eq!(
+        sample_keyframes::<_, si::Kelvin<f64>>(&[], 1.0 * si::S),
+        None
+    );
+
+    let single = [(2.0 * si::S, 5.0 * si::MPS)];
+    assert_eq!(sample_keyframes(&single, 2.0 * si::S), Some(5.0 * si::MPS));
+    assert_eq!(sample_keyframes(&single, 2.1 * si::S), None);
+}
+
+#[test]
+fn jacobian_propagation() {
+    use dim::numeric::{Jacobian, TupleDot};
+
+    // The speed and kinetic energy per unit mass of a body that travels a distance `x` in a time
+    // `t`, linearized about x = 6 m and t = 2 s.
+    let (x, t) = (6.0 * si::M, 2.0 * si::S);
+    let v = x / t;
+    let jacobian = ((1.0 / t, -x / (t * t)), (v / t, -v * v / t));
+
+    let (dv, de) = jacobian.propagate((0.2 * si::M, 0.1 * si::S));
+    let dv: si::MeterPerSecond<f64> = dv;
+    let de: si::Meter2PerSecond2<f64> = de;
+
+    assert!((dv - (0.1 - 0.15) * si::MPS).value_unsafe.abs() < 1e-12);
+    assert!((de - (0.3 - 0.45) * si::M2PS2).value_unsafe.abs() < 1e-12);
+
+    // Each row on its own is a dot product
+    assert_eq!((1.0 / t, -x / (t * t)).dot((0.2 * si::M, 0.1 * si::S)), dv);
+
+    // A single input and output
+    let (dy,) = ((3.0 * si::N / si::M,),).propagate((2.0 * si::M,));
+    assert_eq!(dy, 6.0 * si::N);
+}
+
+#[test]
+fn trapezoid() {
+    use dim::numeric::trapezoid;
+
+    let constant = [3.0 * si::N; 5];
+    let work: si::Joule<f64> = trapezoid(&constant, 0.5 * si::M);
+    assert_eq!(work, 6.0 * si::J);
+
+    // Exact for straight lines
+    let line = [1.0 * si::MPS, 2.0 * si::MPS, 3.0 * si::MPS, 4.0 * si::MPS];
+    assert_eq!(trapezoid(&line, 1.0 * si::S), 7.5 * si::M);
+
+    assert_eq!(trapezoid(&[5.0 * si::MPS], 1.0 * si::S), 0.0 * si::M);
+    assert_eq!(
+        trapezoid::<si::MeterPerSecond<f64>, _>(&[], 1.0 * si::S),
+        0.0 * si::M
+    );
+}
+
+#[test]
+fn blend() {
+    use dim::numeric::blend;
+
+    let targets = [(1.0 * si::M, 0.2), (5.0 * si::M, 0.3), (10.0 * si::M, 0.5)];
+    assert_eq!(blend(&targets), 6.7 * si::M);
+
+    // Two targets lerp between them
+    assert_eq!(
+        blend(&[(2.0 * si::M, 0.75), (6.0 * si::M, 0.25)]),
+        3.0 * si::M
+    );
+    assert_eq!(
+        blend(&[(2.0 * si::M, 1.0), (6.0 * si::M, 0.0)]),
+        2.0 * si::M
+    );
+
+    // Weights are not normalized
+    assert_eq!(blend(&[(3.0 * si::M, 2.0)]), 6.0 * si::M);
+    assert_eq!(blend::<si::Meter<f64>>(&[]), 0.0 * si::M);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn resample() {
+    use dim::numeric::resample;
+
+    let close = |a: &[si::Meter<f64>], b: &[si::Meter<f64>]| {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b)
+                .all(|(&x, &y)| (x - y).value_unsafe.abs() < 1e-12)
+    };
+
+    // A ramp of one meter per second, sampled every 0.25 seconds for two seconds
+    let ramp: Vec<_> = (0..9).map(|i| f64::from(i) * 0.25 * si::M).collect();
+
+    let up = resample(&ramp, 0.25 * si::S, 0.1 * si::S);
+    assert_eq!(up.len(), 21);
+    assert_eq!(up[0], 0.0 * si::M);
+    assert!(close(&up[20..], &[2.0 * si::M]));
+    assert!(close(
+        &up[..4],
+        &[0.0 * si::M, 0.1 * si::M, 0.2 * si::M, 0.3 * si::M]
+    ));
+
+    let down = resample(&ramp, 0.25 * si::S, 0.5 * si::S);
+    assert!(close(
+        &down,
+        &[
+            0.0 * si::M,
+            0.5 * si::M,
+            1.0 * si::M,
+            1.5 * si::M,
+            2.0 * si::M
+        ]
+    ));
+
+    // An end between output samples is dropped
+    let uneven = resample(&ramp, 0.25 * si::S, 0.75 * si::S);
+    assert!(close(&uneven, &[0.0 * si::M, 0.75 * si::M, 1.5 * si::M]));
+
+    assert_eq!(
+        resample(&ramp[..1], 0.25 * si::S, 0.1 * si::S),
+        vec![0.0 * si::M]
+    );
+    assert!(resample::<si::Meter<f64>, _>(&[], si::S, si::S).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "resample requires positive sample periods")]
+fn resample_zero_period() {
+    let _ = dim::numeric::resample(&[1.0 * si::M, 2.0 * si::M], si::S, 0.0 * si::S);
+}
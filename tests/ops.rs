@@ -0,0 +1,685 @@
+extern crate dimensioned as dim;
+
+use dim::si;
+
+#[test]
+fn pow_p() {
+    use dim::typenum::{N1, P2, P3};
+
+    let x = 2.0 * si::M;
+
+    let x2: si::Meter2<f64> = x.pow_p::<P2>();
+    assert_eq!(x2, 4.0 * si::M2);
+
+    let x3: si::Meter3<f64> = x.pow_p::<P3>();
+    assert_eq!(x3, 8.0 * si::M3);
+
+    let xinv: si::PerMeter<f64> = x.pow_p::<N1>();
+    assert_eq!(xinv, 0.5 * si::PM);
+}
+
+#[test]
+fn pow_ratio() {
+    use dim::typenum::{P2, P3};
+
+    let v = 8.0 * si::M3;
+    let a: si::Meter2<f64> = v.pow_ratio::<P2, P3>();
+    assert!((a - 4.0 * si::M2).value_unsafe.abs() < 1e-12);
+
+    let a = 4.0 * si::M2;
+    let v: si::Meter3<f64> = a.pow_ratio::<P3, P2>();
+    assert!((v - 8.0 * si::M3).value_unsafe.abs() < 1e-12);
+
+    // Composes with other arithmetic
+    let t: si::Second<f64> = (9.0 * si::S * si::S).pow_ratio::<P3, P2>() / (3.0 * si::S * si::S);
+    assert!((t - 9.0 * si::S).value_unsafe.abs() < 1e-12);
+}
+
+#[test]
+fn sqrt_and_cbrt() {
+    use dim::cgs;
+    use dim::{Cbrt, Sqrt};
+
+    assert_eq!((9.0 * si::M * si::M).sqrt(), 3.0 * si::M);
+    assert_eq!((6.25 * si::M2 / si::S2).sqrt(), 2.5 * si::MPS);
+    assert_eq!((4.0 * si::ONE).sqrt(), 2.0 * si::ONE);
+    assert_eq!((27.0 * si::M3).cbrt(), 3.0 * si::M);
+    assert_eq!((-8.0 * si::M3 / si::S3).cbrt(), -2.0 * si::MPS);
+
+    // CGS has base units of the square roots of centimeters and grams, so their square roots may
+    // be taken
+    assert_eq!((4.0 * cgs::CM).sqrt(), 2.0 * cgs::SQRTCM);
+    assert_eq!((9.0 * cgs::G).sqrt(), 3.0 * cgs::SQRTG);
+    let e = (16.0 * cgs::ERG).sqrt();
+    assert_eq!(e * e, 16.0 * cgs::ERG);
+}
+
+#[test]
+fn sort_same_units() {
+    use std::cmp::Ordering;
+
+    assert!(1.0 * si::M < 2.0 * si::M);
+    assert!(2.0 * si::M >= 2.0 * si::M);
+    assert_eq!((1.0 * si::M).partial_cmp(&(::std::f64::NAN * si::M)), None);
+
+    let mut lengths = vec![3.0 * si::M, -1.0 * si::M, 2.5 * si::M, 0.0 * si::M];
+    lengths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(
+        lengths,
+        vec![-1.0 * si::M, 0.0 * si::M, 2.5 * si::M, 3.0 * si::M]
+    );
+
+    let mut counts = vec![si::Second::new(3), si::Second::new(1), si::Second::new(2)];
+    counts.sort();
+    assert_eq!(
+        counts,
+        vec![si::Second::new(1), si::Second::new(2), si::Second::new(3)]
+    );
+    assert_eq!(si::Second::new(1).cmp(&si::Second::new(2)), Ordering::Less);
+}
+
+#[test]
+fn partial_cmp_across_value_types() {
+    use std::cmp::Ordering;
+
+    // The primitive floats can't be compared with each other, so we use a type that can be
+    // compared with `f64`.
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Single(f32);
+
+    impl PartialEq<f64> for Single {
+        fn eq(&self, other: &f64) -> bool {
+            f64::from(self.0) == *other
+        }
+    }
+
+    impl PartialOrd<f64> for Single {
+        fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+            f64::from(self.0).partial_cmp(other)
+        }
+    }
+
+    let short = si::Meter::new(Single(1.5));
+    let long = 2.0 * si::M;
+
+    assert_eq!(short.partial_cmp(&long), Some(Ordering::Less));
+    assert!(short < long);
+    assert!(si::Meter::new(Single(2.0)) == long);
+    assert!(si::Meter::new(Single(2.5)) > long);
+}
+
+#[test]
+fn unitless_eq_float() {
+    use dim::cgs;
+
+    let ratio = (1.0 * si::M) / (2.0 * si::M);
+    assert!(ratio == 0.5);
+    assert!(0.5 == ratio);
+    assert!(ratio != 0.25);
+    assert!(0.25 != ratio);
+    assert_eq!(ratio, 0.5);
+    assert!(si::ONE == 1.0);
+    assert!(cgs::Unitless::new(2.0f32) == 2.0f32);
+    assert!(::std::f64::NAN * si::ONE != ::std::f64::NAN);
+}
+
+#[test]
+fn cast_units() {
+    use dim::typenum::{Diff, Sum};
+
+    let x = 3.0 * si::M * si::S / si::S;
+    let y: si::Meter<f64> = x.cast_units();
+    assert_eq!(y, 3.0 * si::M);
+
+    fn mul_div<U1, U2>(x: si::SI<f64, U1>, y: si::SI<f64, U2>) -> si::SI<f64, U1>
+    where
+        U1: std::ops::Add<U2>,
+        U2: Copy,
+        Sum<U1, U2>: std::ops::Sub<U2>,
+        Diff<Sum<U1, U2>, U2>: dim::SameUnits<U1>,
+    {
+        (x * y / y).cast_units()
+    }
+
+    assert_eq!(mul_div(2.0 * si::J, 4.0 * si::S), 2.0 * si::J);
+}
+
+#[test]
+fn clamp_abs() {
+    use dim::ClampAbs;
+
+    let limit = 10.0 * si::N;
+
+    assert_eq!((25.0 * si::N).clamp_abs(limit), 10.0 * si::N);
+    assert_eq!((-25.0 * si::N).clamp_abs(limit), -10.0 * si::N);
+    assert_eq!((10.0 * si::N).clamp_abs(limit), 10.0 * si::N);
+    assert_eq!((-4.0 * si::N).clamp_abs(limit), -4.0 * si::N);
+    assert_eq!((25.0 * si::N).clamp_abs(-limit), 10.0 * si::N);
+    assert!((::std::f64::NAN * si::N)
+        .clamp_abs(limit)
+        .value_unsafe
+        .is_nan());
+
+    assert_eq!(
+        si::Meter::new(-7).clamp_abs(si::Meter::new(5)),
+        si::Meter::new(-5)
+    );
+}
+
+#[test]
+fn clamp_magnitude_with_deadzone() {
+    use dim::ClampDeadzone;
+
+    let (deadzone, max) = (0.5 * si::N, 10.0 * si::N);
+    let condition = |f: si::Newton<f64>| f.clamp_magnitude_with_deadzone(deadzone, max);
+
+    // Inside the deadzone
+    assert_eq!(condition(0.2 * si::N), 0.0 * si::N);
+    assert_eq!(condition(-0.4 * si::N), 0.0 * si::N);
+    assert!(condition(-0.4 * si::N).value_unsafe.is_sign_positive());
+    assert_eq!((-0.4f64).clamp_magnitude_with_deadzone(0.5, 10.0), 0.0);
+
+    // Mid-range
+    assert_eq!(condition(0.5 * si::N), 0.5 * si::N);
+    assert_eq!(condition(-4.0 * si::N), -4.0 * si::N);
+
+    // Above the maximum
+    assert_eq!(condition(25.0 * si::N), 10.0 * si::N);
+    assert_eq!(condition(-25.0 * si::N), -10.0 * si::N);
+
+    assert!(condition(::std::f64::NAN * si::N).value_unsafe.is_nan());
+
+    assert_eq!(
+        si::Meter::new(2).clamp_magnitude_with_deadzone(si::Meter::new(3), si::Meter::new(5)),
+        si::Meter::new(0)
+    );
+}
+
+#[test]
+fn into_dimensionless() {
+    use dim::{cgs, IntoDimensionless};
+
+    fn ratio<X: IntoDimensionless<si::Unitless<f64>>>(x: X) -> si::Meter<f64> {
+        x.into_dimensionless() * si::M
+    }
+
+    assert_eq!(ratio(0.25), 0.25 * si::M);
+    assert_eq!(ratio(0.25 * si::ONE), 0.25 * si::M);
+    assert_eq!(ratio((1.0 * si::S) / (4.0 * si::S)), 0.25 * si::M);
+
+    let x: cgs::Unitless<f32> = 1.5f32.into_dimensionless();
+    assert_eq!(x, cgs::Unitless::new(1.5f32));
+}
+
+#[test]
+fn unitless_into_float() {
+    use dim::cgs;
+
+    let ratio = (3.0 * si::M) / (2.0 * si::M);
+    let x: f64 = ratio.into();
+    assert_eq!(x, 1.5);
+    assert_eq!(f64::from(4.0 * si::ONE), 4.0);
+
+    let y: f32 = (cgs::Second::new(1.0f32) / cgs::Second::new(4.0f32)).into();
+    assert_eq!(y, 0.25);
+}
+
+#[test]
+fn clamp_abs_saturated() {
+    use dim::ClampAbs;
+
+    let limit = 2.0 * si::MPS;
+
+    assert_eq!(
+        (3.0 * si::MPS).clamp_abs_saturated(limit),
+        (2.0 * si::MPS, true)
+    );
+    assert_eq!(
+        (-3.0 * si::MPS).clamp_abs_saturated(limit),
+        (-2.0 * si::MPS, true)
+    );
+    assert_eq!(
+        (1.5 * si::MPS).clamp_abs_saturated(limit),
+        (1.5 * si::MPS, false)
+    );
+    assert_eq!(
+        (-2.0 * si::MPS).clamp_abs_saturated(limit),
+        (-2.0 * si::MPS, false)
+    );
+    assert_eq!(
+        si::Second::new(9).clamp_abs_saturated(si::Second::new(4)),
+        (si::Second::new(4), true)
+    );
+
+    let (nan, saturated) = (::std::f64::NAN * si::MPS).clamp_abs_saturated(limit);
+    assert!(nan.value_unsafe.is_nan());
+    assert!(!saturated);
+}
+
+#[test]
+fn nearest_in() {
+    use dim::NearestIn;
+
+    let allowed = [10.0 * si::OHM, 22.0 * si::OHM, 47.0 * si::OHM];
+
+    assert_eq!((0.0 * si::OHM).nearest_in(&allowed), 10.0 * si::OHM);
+    assert_eq!((20.0 * si::OHM).nearest_in(&allowed), 22.0 * si::OHM);
+    assert_eq!((35.0 * si::OHM).nearest_in(&allowed), 47.0 * si::OHM);
+    // Ties go to the first allowed value
+    assert_eq!((16.0 * si::OHM).nearest_in(&allowed), 10.0 * si::OHM);
+    assert_eq!((1e6 * si::OHM).nearest_in(&allowed), 47.0 * si::OHM);
+    assert_eq!((5.0 * si::OHM).nearest_in(&allowed[2..]), 47.0 * si::OHM);
+}
+
+#[test]
+#[should_panic]
+fn nearest_in_empty() {
+    use dim::NearestIn;
+
+    (1.0 * si::M).nearest_in(&[]);
+}
+
+#[test]
+fn is_near_zero() {
+    use dim::NearZero;
+
+    let tolerance = 0.01 * si::V;
+
+    assert!((0.0 * si::V).is_near_zero(tolerance));
+    assert!((0.0099 * si::V).is_near_zero(tolerance));
+    assert!((-0.0099 * si::V).is_near_zero(tolerance));
+    assert!((0.01 * si::V).is_near_zero(tolerance));
+    assert!(!(0.0101 * si::V).is_near_zero(tolerance));
+    assert!(!(-0.0101 * si::V).is_near_zero(tolerance));
+    assert!(!(::std::f64::NAN * si::V).is_near_zero(tolerance));
+
+    assert!(si::Meter::new(-2).is_near_zero(si::Meter::new(2)));
+    assert!(!si::Meter::new(3).is_near_zero(si::Meter::new(2)));
+}
+
+#[test]
+fn in_range() {
+    use dim::InRange;
+
+    let (lo, hi) = (2.0 * si::S, 5.0 * si::S);
+
+    assert!((2.0 * si::S).in_range(lo, hi));
+    assert!((3.5 * si::S).in_range(lo, hi));
+    assert!((5.0 * si::S).in_range(lo, hi));
+    assert!(!(1.9 * si::S).in_range(lo, hi));
+    assert!(!(5.1 * si::S).in_range(lo, hi));
+    assert!(!(::std::f64::NAN * si::S).in_range(lo, hi));
+    assert!(si::Meter::new(3).in_range(si::Meter::new(3), si::Meter::new(3)));
+}
+
+#[test]
+fn ops_by_reference() {
+    let f = 3.0 * si::N;
+    let d = 2.0 * si::M;
+
+    let w: si::Joule<f64> = &f * &d;
+    assert_eq!(w, 6.0 * si::J);
+    assert_eq!(&w / &d, f);
+    assert_eq!(&f + &f, 6.0 * si::N);
+    assert_eq!(&f - &f, 0.0 * si::N);
+
+    // Generic code that only borrows its arguments
+    fn work<'a, F, D>(force: &'a F, distance: &'a D) -> <&'a F as std::ops::Mul<&'a D>>::Output
+    where
+        &'a F: std::ops::Mul<&'a D>,
+    {
+        force * distance
+    }
+    assert_eq!(work::<si::Newton<f64>, si::Meter<f64>>(&f, &d), w);
+
+    let forces = [f, 2.0 * f];
+    let total = forces.iter().fold(0.0 * si::J, |acc, f| acc + f * &d);
+    assert_eq!(total, 18.0 * si::J);
+}
+
+#[test]
+fn approach() {
+    use dim::Approach;
+
+    let step = 0.5 * si::K;
+
+    // Clamped
+    assert_eq!((20.0 * si::K).approach(25.0 * si::K, step), 20.5 * si::K);
+    assert_eq!((20.0 * si::K).approach(15.0 * si::K, step), 19.5 * si::K);
+    assert_eq!((20.0 * si::K).approach(25.0 * si::K, -step), 20.5 * si::K);
+
+    // Unclamped
+    assert_eq!((20.0 * si::K).approach(20.2 * si::K, step), 20.2 * si::K);
+    assert_eq!((20.0 * si::K).approach(19.5 * si::K, step), 19.5 * si::K);
+    assert_eq!((20.0 * si::K).approach(20.0 * si::K, step), 20.0 * si::K);
+
+    let mut x = si::Meter::new(0);
+    for _ in 0..4 {
+        x = x.approach(si::Meter::new(10), si::Meter::new(3));
+    }
+    assert_eq!(x, si::Meter::new(10));
+}
+
+#[test]
+fn clamp_reporting() {
+    use dim::{ClampReporting, Clamped};
+
+    let lo = 1.0 * si::BAR;
+    let hi = 3.0 * si::BAR;
+
+    assert_eq!(
+        (2.0 * si::BAR).clamp_reporting(lo, hi),
+        (2.0 * si::BAR, Clamped::None)
+    );
+    assert_eq!(
+        (0.5 * si::BAR).clamp_reporting(lo, hi),
+        (1.0 * si::BAR, Clamped::Low)
+    );
+    assert_eq!(
+        (4.0 * si::BAR).clamp_reporting(lo, hi),
+        (3.0 * si::BAR, Clamped::High)
+    );
+    assert_eq!(lo.clamp_reporting(lo, hi), (lo, Clamped::None));
+    assert_eq!(hi.clamp_reporting(lo, hi), (hi, Clamped::None));
+
+    let (nan, clamped) = (::std::f64::NAN * si::BAR).clamp_reporting(lo, hi);
+    assert!(nan.value_unsafe.is_nan());
+    assert_eq!(clamped, Clamped::None);
+
+    assert_eq!(
+        si::Second::new(-3).clamp_reporting(si::Second::new(0), si::Second::new(5)),
+        (si::Second::new(0), Clamped::Low)
+    );
+}
+
+#[test]
+fn ordered_values_in_btreeset() {
+    use std::cmp::Ordering;
+    use std::collections::BTreeSet;
+    use std::ops::{Add, Mul};
+
+    // A stand-in for `ordered_float::OrderedFloat`, ordering `NaN` above everything else
+    #[derive(Copy, Clone, Debug)]
+    struct Ordered(f64);
+
+    impl PartialEq for Ordered {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+    impl Eq for Ordered {}
+    impl PartialOrd for Ordered {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Ordered {
+        fn cmp(&self, other: &Self) -> Ordering {
+            match (self.0.is_nan(), other.0.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.0.partial_cmp(&other.0).unwrap(),
+            }
+        }
+    }
+    impl Add for Ordered {
+        type Output = Ordered;
+        fn add(self, rhs: Ordered) -> Ordered {
+            Ordered(self.0 + rhs.0)
+        }
+    }
+    impl Mul for Ordered {
+        type Output = Ordered;
+        fn mul(self, rhs: Ordered) -> Ordered {
+            Ordered(self.0 * rhs.0)
+        }
+    }
+
+    let m = |x| si::Meter::new(Ordered(x));
+
+    let mut set = BTreeSet::new();
+    for &x in &[3.0, 1.0, ::std::f64::NAN, 2.0, 1.0] {
+        set.insert(m(x));
+    }
+    assert_eq!(set.len(), 4);
+    let sorted: Vec<f64> = set.iter().map(|x| x.value_unsafe.0).collect();
+    assert_eq!(&sorted[..3], &[1.0, 2.0, 3.0]);
+    assert!(sorted[3].is_nan());
+
+    assert_eq!(m(1.0) + m(2.0), m(3.0));
+    let area: si::Meter2<Ordered> = m(2.0) * m(3.0);
+    assert_eq!(area, si::Meter2::new(Ordered(6.0)));
+    assert_eq!(m(1.0).max(m(2.0)), m(2.0));
+}
+
+#[test]
+fn trig_of_unitless() {
+    use std::f64::consts::PI;
+
+    let s: si::Unitless<f64> = (0.0 * si::ONE).sin();
+    assert_eq!(s, 0.0);
+    assert_eq!((0.0 * si::ONE).cos(), 1.0);
+    assert!((*(PI / 4.0 * si::ONE).tan() - 1.0).abs() < 1e-12);
+    assert_eq!((1.0 * si::ONE).asin(), PI / 2.0);
+    assert_eq!((1.0 * si::ONE).acos(), 0.0);
+    assert_eq!(si::Unitless::new(0.0f32).atan(), 0.0f32);
+
+    let angle: si::Unitless<f64> = (1.0 * si::M).atan2(1.0 * si::M);
+    assert_eq!(angle, PI / 4.0);
+    assert_eq!((-1.0 * si::ONE).atan2(-1.0 * si::ONE), -3.0 * PI / 4.0);
+}
+
+#[test]
+fn trig_of_dereferenced_unitless() {
+    use std::f64::consts::PI;
+
+    let (x, x0) = (PI * si::M, 2.0 * si::M);
+    let y: f64 = (*(x / x0)).sin();
+    assert_eq!(y, 1.0);
+
+    let q = 0.0 * si::ONE;
+    let c: f64 = (*q).cos();
+    assert_eq!(c, 1.0);
+    assert_eq!((*q).atan2(1.0), 0.0);
+}
+
+#[test]
+fn powf_of_unitless() {
+    let x: si::Unitless<f64> = (8.0 * si::ONE).powf(1.0 / 3.0);
+    assert!((*x - 2.0).abs() < 1e-12);
+    assert_eq!(si::Unitless::new(4.0f32).powf(0.5), 2.0f32);
+    assert_eq!((2.0 * si::ONE).powf(-1.0), 0.5);
+}
+
+#[test]
+fn exp_and_logs_of_unitless() {
+    use std::f64::consts::E;
+
+    let e: si::Unitless<f64> = (1.0 * si::ONE).exp();
+    assert!((*e - E).abs() < 1e-12);
+    assert!((*(E * si::ONE).ln() - 1.0).abs() < 1e-12);
+    assert_eq!((1000.0 * si::ONE).log10(), 3.0);
+    assert_eq!((8.0 * si::ONE).log2(), 3.0);
+    assert_eq!(si::Unitless::new(0.0f32).exp(), 1.0f32);
+    assert_eq!((1.0 * si::ONE).ln(), 0.0);
+
+    // The ratio of two quantities with the same units is unitless
+    assert_eq!((100.0 * si::M / (1.0 * si::M)).log10(), 2.0);
+}
+
+#[test]
+fn rem_by_scalar() {
+    let x: si::Meter<f64> = 23.0 * si::M % 10.0;
+    assert_eq!(x, 3.0 * si::M);
+    assert_eq!(-23.0 * si::M % 10.0, -3.0 * si::M);
+    assert_eq!(7.5 * si::MPS % 2.0, 1.5 * si::MPS);
+
+    let mut t = 125.0 * si::S;
+    t %= 60.0;
+    assert_eq!(t, 5.0 * si::S);
+
+    let index: si::Second<usize> = si::Second::new(17usize) % 5;
+    assert_eq!(index, si::Second::new(2));
+}
+
+#[test]
+fn neg() {
+    assert_eq!(-(3.0 * si::M), -3.0 * si::M);
+    assert_eq!(-(-3.0 * si::M), 3.0 * si::M);
+
+    let v: si::MeterPerSecond<f64> = -(2.5 * si::MPS);
+    assert_eq!(v, -2.5 * si::MPS);
+    assert_eq!(-(si::Second::new(4i32)), si::Second::new(-4));
+    assert_eq!(-(1.0 * si::ONE), -1.0);
+}
+
+#[test]
+fn assign_ops() {
+    let steps = [1.0 * si::M, 2.5 * si::M, -0.5 * si::M];
+    let mut position = 0.0 * si::M;
+    for &step in &steps {
+        position += step;
+    }
+    assert_eq!(position, 3.0 * si::M);
+
+    for &step in &steps {
+        position -= step;
+    }
+    assert_eq!(position, 0.0 * si::M);
+
+    let mut x = 1.5 * si::M;
+    x *= 4.0;
+    assert_eq!(x, 6.0 * si::M);
+    x /= 3.0;
+    assert_eq!(x, 2.0 * si::M);
+
+    let mut count = si::Second::new(10i32);
+    count += si::Second::new(5);
+    count *= 2;
+    assert_eq!(count, si::Second::new(30));
+}
+
+#[test]
+fn clamp_or() {
+    use dim::ClampOr;
+    use std::f64::NAN;
+
+    let (lo, hi, fallback) = (-1.0 * si::V, 1.0 * si::V, 0.5 * si::V);
+
+    assert_eq!((0.25 * si::V).clamp_or(lo, hi, fallback), 0.25 * si::V);
+    assert_eq!((-3.0 * si::V).clamp_or(lo, hi, fallback), lo);
+    assert_eq!((3.0 * si::V).clamp_or(lo, hi, fallback), hi);
+    assert_eq!((1.0 * si::V).clamp_or(lo, lo, fallback), lo);
+
+    assert_eq!((NAN * si::V).clamp_or(lo, hi, fallback), fallback);
+    assert_eq!((0.0 * si::V).clamp_or(NAN * si::V, hi, fallback), fallback);
+    assert_eq!((0.0 * si::V).clamp_or(lo, NAN * si::V, fallback), fallback);
+    assert_eq!((0.0 * si::V).clamp_or(hi, lo, fallback), fallback);
+
+    assert_eq!(7.clamp_or(0, 5, -1), 5);
+}
+
+#[test]
+fn rem_same_units() {
+    let r: si::Second<f64> = 7.0 * si::S % (3.0 * si::S);
+    assert_eq!(r, 1.0 * si::S);
+    assert_eq!(-7.0 * si::S % (3.0 * si::S), -1.0 * si::S);
+    assert_eq!(si::Second::new(7) % si::Second::new(3), si::Second::new(1));
+
+    let mut angle = 7.5 * si::RAD;
+    angle %= 2.0 * si::RAD;
+    assert_eq!(angle, 1.5 * si::RAD);
+}
+
+#[test]
+fn value_accessors() {
+    let mut x = 3.0 * si::M;
+    assert_eq!(*x.value(), 3.0);
+
+    *x.value_mut() *= 2.0;
+    assert_eq!(x, 6.0 * si::M);
+
+    let v: f64 = x.into_inner();
+    assert_eq!(v, 6.0);
+
+    let ratio: f64 = (x / (2.0 * si::M)).into();
+    assert_eq!(ratio, 3.0);
+
+    let count: i64 = (si::Meter::new(12i64) / si::Meter::new(4i64)).into();
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn map_keeps_units() {
+    let x: si::Meter<f64> = (2.7 * si::M).map(|v| v.round());
+    assert_eq!(x, 3.0 * si::M);
+
+    let f = (1.5 * si::N).map(|v| v as i32);
+    assert_eq!(f, si::Newton::new(1));
+    assert_eq!(format!("{}", f), "1 N");
+
+    assert_eq!(si::Meter::new(-3).map(i32::abs), si::Meter::new(3));
+}
+
+#[test]
+fn min_max_clamp() {
+    let (a, b) = (2.0 * si::M, 5.0 * si::M);
+    assert_eq!(a.min(b), a);
+    assert_eq!(b.min(a), a);
+    assert_eq!(a.max(b), b);
+    assert_eq!(b.max(a), b);
+
+    assert_eq!((5.0 * si::M).clamp(1.0 * si::M, 3.0 * si::M), 3.0 * si::M);
+    assert_eq!((0.0 * si::M).clamp(1.0 * si::M, 3.0 * si::M), 1.0 * si::M);
+    assert_eq!((2.0 * si::M).clamp(1.0 * si::M, 3.0 * si::M), 2.0 * si::M);
+    assert!((::std::f64::NAN * si::M)
+        .clamp(1.0 * si::M, 3.0 * si::M)
+        .value_unsafe
+        .is_nan());
+
+    assert_eq!(
+        si::Second::new(7).min(si::Second::new(3)),
+        si::Second::new(3)
+    );
+    assert_eq!(
+        si::Second::new(7).clamp(si::Second::new(0), si::Second::new(5)),
+        si::Second::new(5)
+    );
+}
+
+#[test]
+fn min_max_clamp_of_unitless() {
+    let ratio = 1.5 * si::ONE;
+    assert_eq!(ratio.min(1.0 * si::ONE), 1.0);
+    assert_eq!(ratio.max(2.0 * si::ONE), 2.0);
+
+    // Dereferencing gives the value's own methods, which take bare values
+    let bare: f64 = (*ratio).min(1.0);
+    assert_eq!(bare, 1.0);
+    assert_eq!((*ratio).max(0.0), 1.5);
+}
+
+#[test]
+#[should_panic(expected = "clamp requires lo <= hi")]
+fn clamp_inverted_bounds() {
+    let _ = (2.0 * si::M).clamp(3.0 * si::M, 1.0 * si::M);
+}
+
+#[test]
+fn abs_and_signum() {
+    use dim::{Abs, Signum};
+
+    assert_eq!((-3.0 * si::M).abs(), 3.0 * si::M);
+    assert_eq!((3.0 * si::M).abs(), 3.0 * si::M);
+    assert_eq!((-2.5f32 * si::f32consts::N).abs(), 2.5 * si::f32consts::N);
+
+    assert_eq!((-3.0 * si::M).signum(), -1.0 * si::ONE);
+    assert_eq!((3.0 * si::M).signum(), 1.0 * si::ONE);
+    assert_eq!((0.0 * si::M).signum(), 1.0 * si::ONE);
+    assert_eq!((-0.0 * si::M).signum(), -1.0 * si::ONE);
+    assert!((::std::f64::NAN * si::M).signum().value_unsafe.is_nan());
+
+    let sign: si::Unitless<f32> = (-2.0f32 * si::f32consts::S).signum();
+    assert_eq!(sign, -1.0);
+}
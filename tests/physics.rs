@@ -0,0 +1,107 @@
+extern crate dimensioned as dim;
+
+use dim::{cgs, si, Abs};
+
+#[test]
+fn rotational_kinematics() {
+    use dim::physics::{angular_velocity, centripetal_acceleration, linear_velocity};
+
+    // A wheel turning at 10 rad/s with a radius of 0.3 m
+    let w = 10.0 * si::RAD / si::S;
+    let r = 0.3 * si::M;
+
+    let v: si::MeterPerSecond<f64> = linear_velocity(w, r);
+    assert_eq!(v, 3.0 * si::MPS);
+
+    let w2: si::Hertz<f64> = angular_velocity(v, r);
+    assert_eq!(w2, w);
+
+    let a: si::MeterPerSecond2<f64> = centripetal_acceleration(v, r);
+    assert!((a - 30.0 * si::MPS2).abs() < 1e-12 * si::MPS2);
+
+    let v_cgs = linear_velocity(2.0 / cgs::S, 5.0 * cgs::CM);
+    assert_eq!(v_cgs, 10.0 * cgs::CMPS);
+}
+
+#[test]
+fn exponential_factors() {
+    use dim::physics::{exp_decay, exp_growth};
+
+    let tau = 4.0 * si::S;
+    let initial = 3.0 * si::A;
+
+    assert_eq!(exp_decay(0.0 * si::S, tau), 1.0);
+    assert_eq!(exp_growth(0.0 * si::S, tau), 1.0);
+    assert_eq!(initial * exp_decay(0.0 * si::S, tau), initial);
+
+    let e = ::std::f64::consts::E;
+    assert!((exp_decay(tau, tau) - 1.0 / e).abs() < 1e-15);
+    assert!((exp_growth(tau, tau) - e).abs() < 1e-15);
+    assert!((exp_decay(2.0 * tau, tau) * exp_growth(2.0 * tau, tau) - 1.0).abs() < 1e-15);
+
+    let remaining: si::Ampere<f64> = initial * exp_decay(tau, tau);
+    assert!((remaining - initial / e).abs() < 1e-15 * si::A);
+
+    assert_eq!(exp_decay(2.0 * cgs::S, 2.0 * cgs::S), exp_decay(tau, tau));
+}
+
+#[test]
+fn density_round_trip() {
+    use dim::physics::{density, mass_from_density_volume};
+
+    let m = 19.3 * si::KG;
+    let v = 0.001 * si::M3;
+
+    let rho: si::KilogramPerMeter3<f64> = density(m, v);
+    assert!((rho - 19300.0 * si::KGPM3).abs() < 1e-9 * si::KGPM3);
+
+    let m2: si::Kilogram<f64> = mass_from_density_volume(rho, v);
+    assert!((m2 - m).abs() < 1e-12 * si::KG);
+    assert!((density(mass_from_density_volume(rho, v), v) - rho).abs() < 1e-9 * si::KGPM3);
+
+    let rho_cgs = density(2.0 * cgs::G, 4.0 * cgs::CM * cgs::CM * cgs::CM);
+    assert_eq!(rho_cgs, 0.5 * cgs::G / (cgs::CM * cgs::CM * cgs::CM));
+    assert_eq!(
+        mass_from_density_volume(rho_cgs, 4.0 * cgs::CM * cgs::CM * cgs::CM),
+        2.0 * cgs::G
+    );
+}
+
+#[test]
+fn rotational_dynamics() {
+    use dim::physics::{angular_acceleration, moment_of_inertia, torque};
+
+    // A 4 N force on a 0.5 m wrench
+    let tau: si::Joule<f64> = torque(4.0 * si::N, 0.5 * si::M);
+    assert_eq!(tau, 2.0 * si::N * si::M);
+
+    // Two 1 kg masses at either end of a 2 m rod
+    let inertia = moment_of_inertia(1.0 * si::KG, 1.0 * si::M) * 2.0;
+    assert_eq!(inertia, 2.0 * si::KG * si::M2);
+
+    let alpha: si::PerSecond2<f64> = angular_acceleration(tau, inertia);
+    assert_eq!(alpha, 1.0 * si::RAD / si::S2);
+    assert_eq!(alpha * inertia, tau);
+
+    let tau_cgs = torque(3.0 * cgs::DYN, 2.0 * cgs::CM);
+    assert_eq!(tau_cgs, 6.0 * cgs::ERG);
+    let inertia_cgs: cgs::GramCentimeter2<f64> = moment_of_inertia(3.0 * cgs::G, 1.0 * cgs::CM);
+    let alpha_cgs: cgs::PerSecond2<f64> = angular_acceleration(tau_cgs, inertia_cgs);
+    assert_eq!(alpha_cgs, 2.0 / (cgs::S * cgs::S));
+}
+
+#[test]
+fn conductance_and_resistance() {
+    use dim::physics::{conductance, resistance};
+
+    let r = 8.0 * si::OHM;
+    let g = conductance(r);
+    assert_eq!(g, 0.125 * si::SIE);
+    assert_eq!(g, 1.0 / r);
+    assert_eq!(resistance(g), r);
+
+    // Siemens are reciprocal ohms, so the product is dimensionless
+    assert_eq!(r * g, 1.0 * si::ONE);
+    assert_eq!(si::V / si::A, si::OHM);
+    assert_eq!(si::A / si::V, si::SIE);
+}
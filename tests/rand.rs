@@ -0,0 +1,42 @@
+#![cfg(feature = "rand")]
+
+extern crate dimensioned as dim;
+
+use dim::rand::distributions::{Distribution, Uniform};
+use dim::rand::prng::XorShiftRng;
+use dim::rand::{Rng, SeedableRng};
+use dim::si;
+
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([7; 16])
+}
+
+#[test]
+fn gen_range() {
+    let mut rng = rng();
+    let (lo, hi) = (2.0 * si::M, 5.0 * si::M);
+
+    for _ in 0..1000 {
+        let x: si::Meter<f64> = rng.gen_range(lo, hi);
+        assert!(lo <= x && x < hi);
+    }
+}
+
+#[test]
+fn uniform() {
+    let mut rng = rng();
+
+    let lengths = Uniform::new(-1.0 * si::M, 1.0 * si::M);
+    for _ in 0..1000 {
+        let x = lengths.sample(&mut rng);
+        assert!(-1.0 * si::M <= x && x < 1.0 * si::M);
+    }
+
+    // The upper bound of an inclusive range is sampled
+    let counts = Uniform::new_inclusive(si::Second::new(1u32), si::Second::new(2u32));
+    let samples: Vec<_> = counts.sample_iter(&mut rng).take(100).collect();
+    assert!(samples
+        .iter()
+        .all(|&t| t == si::Second::new(1) || t == si::Second::new(2)));
+    assert!(samples.contains(&si::Second::new(2)));
+}
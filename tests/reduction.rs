@@ -0,0 +1,55 @@
+#[macro_use]
+extern crate dimensioned as dim;
+
+use dim::cgs::{self, CGS};
+use dim::si::{self, SI};
+use dim::ucum;
+
+// These tests pass by compiling; they guard against regressions in the type-level arithmetic.
+
+#[test]
+fn si_reductions() {
+    dimension_reduces_to!(si: Meter = Newton * Meter / Newton);
+    dimension_reduces_to!(si: Unitless = Meter / Meter);
+    dimension_reduces_to!(si: Newton = Kilogram * Meter / Second2);
+    dimension_reduces_to!(si: Newton = Kilogram * MeterPerSecond2);
+    dimension_reduces_to!(si: Joule = Newton * Meter);
+    dimension_reduces_to!(si: Joule = Kilogram * Meter2 / Second2);
+    dimension_reduces_to!(si: Watt = Joule / Second);
+    dimension_reduces_to!(si: Pascal = Newton / Meter / Meter);
+    dimension_reduces_to!(si: Volt = Joule / Coulomb);
+    dimension_reduces_to!(si: Ohm = Volt / Ampere);
+    dimension_reduces_to!(si: Siemens = Unitless / Ohm);
+    dimension_reduces_to!(si: Hertz = Unitless / Second);
+    dimension_reduces_to!(si: Second = Unitless / Hertz);
+    dimension_reduces_to!(si: Meter3 = Meter * Meter * Meter);
+    dimension_reduces_to!(si: MeterPerSecond = Meter * Hertz);
+}
+
+#[test]
+fn ucum_reductions() {
+    dimension_reduces_to!(ucum: Steradian = Radian * Radian);
+    dimension_reduces_to!(ucum: MilliJoule = MilliNewton * Meter);
+    dimension_reduces_to!(ucum: Ampere = Coulomb / Second);
+}
+
+#[test]
+fn cgs_reductions() {
+    dimension_reduces_to!(cgs: Centimeter = SqrtCentimeter * SqrtCentimeter);
+    dimension_reduces_to!(cgs: Dyne = Gram * Centimeter / Second2);
+    dimension_reduces_to!(cgs: Erg = Dyne * Centimeter);
+    dimension_reduces_to!(cgs: StatAmpere = StatCoulomb / Second);
+}
+
+#[test]
+fn dimensional_equations() {
+    dimensional_equation!(si, SI: Force = Kilogram * MeterPerSecond2);
+    dimensional_equation!(si, SI: Force = Kilogram * Meter / Second2);
+    dimensional_equation!(si, SI: Energy = Newton * Meter);
+    dimensional_equation!(si, SI: Power = Joule / Second);
+    dimensional_equation!(si, SI: Power = Volt * Ampere);
+    dimensional_equation!(si, SI: Velocity = Meter / Second);
+    dimensional_equation!(si, SI: Acceleration = MeterPerSecond / Second);
+    dimensional_equation!(cgs, CGS: Force = Gram * Centimeter / Second2);
+    dimensional_equation!(cgs, CGS: Energy = Dyne * Centimeter);
+}
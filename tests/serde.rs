@@ -0,0 +1,138 @@
+#![cfg(all(feature = "serde", feature = "serde_test"))]
+
+extern crate dimensioned as dim;
+extern crate serde_test;
+
+use dim::{cgs, si};
+use serde_test::{assert_tokens, Token};
+
+// A serializer sees exactly the same calls for a quantity as for its raw value, so any format,
+// including binary ones, writes the same bytes for both.
+
+#[test]
+fn serialize_as_raw_value() {
+    assert_tokens(&2.5, &[Token::F64(2.5)]);
+    assert_tokens(&(2.5 * si::N), &[Token::F64(2.5)]);
+    assert_tokens(&(2.5 * si::MPS2), &[Token::F64(2.5)]);
+    assert_tokens(&(2.5 * cgs::DYN), &[Token::F64(2.5)]);
+
+    assert_tokens(&si::Meter::new(2.5f32), &[Token::F32(2.5)]);
+    assert_tokens(&si::Second::new(-7i64), &[Token::I64(-7)]);
+    assert_tokens(&si::Second::new(7u8), &[Token::U8(7)]);
+}
+
+#[test]
+fn serialize_nested_as_raw_values() {
+    let raw = [1.0, 2.0];
+    let quantities = [1.0 * si::J, 2.0 * si::J];
+    let tokens = [
+        Token::Tuple { len: 2 },
+        Token::F64(1.0),
+        Token::F64(2.0),
+        Token::TupleEnd,
+    ];
+
+    assert_tokens(&raw, &tokens);
+    assert_tokens(&quantities, &tokens);
+}
+
+#[cfg(feature = "std")]
+mod with_units {
+    use dim::fmt::PrintUnits;
+    use dim::serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use dim::serde_units::{self, units_string};
+    use dim::{cgs, si, Dimensioned};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+    // Stands in for a field with `#[serde(with = "dim::serde_units")]`
+    #[derive(Debug, PartialEq)]
+    struct WithUnits<Q>(Q);
+
+    impl<Q> Serialize for WithUnits<Q>
+    where
+        Q: Dimensioned + PrintUnits,
+        Q::Value: Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serde_units::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de, Q> Deserialize<'de> for WithUnits<Q>
+    where
+        Q: Dimensioned + PrintUnits,
+        Q::Value: Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            serde_units::deserialize(deserializer).map(WithUnits)
+        }
+    }
+
+    fn tokens(value: f64, units: &'static str) -> [Token; 6] {
+        [
+            Token::Struct {
+                name: "Quantity",
+                len: 2,
+            },
+            Token::Str("value"),
+            Token::F64(value),
+            Token::Str("units"),
+            Token::Str(units),
+            Token::StructEnd,
+        ]
+    }
+
+    #[test]
+    fn units_strings() {
+        assert_eq!(units_string::<si::MeterPerSecond2<f64>>(), "m*s^-2");
+        assert_eq!(units_string::<si::Newton<f64>>(), "N");
+        assert_eq!(units_string::<si::Unitless<f64>>(), "");
+        assert_eq!(units_string::<cgs::StatCoulomb<f64>>(), "cm^3/2*g^1/2*s^-1");
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_tokens(&WithUnits(9.8 * si::MPS2), &tokens(9.8, "m*s^-2"));
+        assert_tokens(&WithUnits(2.0 * si::N), &tokens(2.0, "N"));
+        assert_tokens(&WithUnits(0.5 * si::ONE), &tokens(0.5, ""));
+    }
+
+    #[test]
+    fn fields_in_any_order() {
+        assert_de_tokens(
+            &WithUnits(2.0 * si::N),
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("units"),
+                Token::Str("N"),
+                Token::Str("value"),
+                Token::F64(2.0),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn mismatched_units() {
+        assert_de_tokens_error::<WithUnits<si::Meter<f64>>>(
+            &tokens(9.8, "m*s^-2"),
+            "expected units `m`, found `m*s^-2`",
+        );
+    }
+
+    #[test]
+    fn missing_units() {
+        assert_de_tokens_error::<WithUnits<si::Meter<f64>>>(
+            &[
+                Token::Struct {
+                    name: "Quantity",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::F64(1.0),
+                Token::StructEnd,
+            ],
+            "missing field `units`",
+        );
+    }
+}
@@ -0,0 +1,161 @@
+#[macro_use]
+extern crate dimensioned as dim;
+
+use dim::si;
+
+#[test]
+fn pressure_units() {
+    use dim::dimensions::Pressure;
+
+    fn pressure<P: Pressure>(_: P) {}
+    pressure(si::PA);
+
+    dimension_reduces_to!(si: Pascal = Kilogram / Meter / Second2);
+    dimension_reduces_to!(si: Pascal = Newton / Meter2);
+
+    assert_eq!(format!("{}", 1.0 * si::PA), "1 Pa");
+    assert_eq!(format!("{}", 1.0 * si::PAS), "1 m^-1*kg*s^-1");
+    assert_eq!(1.0 * si::BAR, 1e5 * si::PA);
+    assert_eq!(1.0 * si::MBAR, 100.0 * si::PA);
+    assert_eq!(1.0 * si::N / (2.0 * si::M2), 0.5 * si::PA);
+}
+
+#[test]
+fn electrical_units() {
+    use dim::dimensions::{
+        Capacitance, Charge, Conductance, Current, ElectricPotential, Inductance, MagneticFlux,
+        Resistance,
+    };
+
+    dimension_reduces_to!(si: Coulomb = Ampere * Second);
+    dimension_reduces_to!(si: Volt = Kilogram * Meter2 / Ampere / Second3);
+    dimension_reduces_to!(si: Ohm = Kilogram * Meter2 / Ampere / Ampere / Second3);
+    dimension_reduces_to!(si: Siemens = Ampere * Ampere * Second3 / Kilogram / Meter2);
+    dimension_reduces_to!(si: Farad = Ampere * Ampere * Second4 / Kilogram / Meter2);
+    dimension_reduces_to!(si: Henry = Kilogram * Meter2 / Ampere / Ampere / Second2);
+    dimension_reduces_to!(si: Weber = Kilogram * Meter2 / Ampere / Second2);
+    dimension_reduces_to!(si: Tesla = Kilogram / Ampere / Second2);
+
+    fn current<Q: Current>(_: Q) {}
+    fn charge<Q: Charge>(_: Q) {}
+    fn potential<Q: ElectricPotential>(_: Q) {}
+    fn resistance<Q: Resistance>(_: Q) {}
+    fn conductance<Q: Conductance>(_: Q) {}
+    fn capacitance<Q: Capacitance>(_: Q) {}
+    fn inductance<Q: Inductance>(_: Q) {}
+    fn flux<Q: MagneticFlux>(_: Q) {}
+    current(si::A);
+    charge(si::C);
+    potential(si::V);
+    resistance(si::OHM);
+    conductance(si::SIE);
+    capacitance(si::F);
+    inductance(si::H);
+    flux(si::WB);
+
+    let tokens = [
+        format!("{}", 1.0 * si::A),
+        format!("{}", 1.0 * si::C),
+        format!("{}", 1.0 * si::V),
+        format!("{}", 1.0 * si::OHM),
+        format!("{}", 1.0 * si::F),
+        format!("{}", 1.0 * si::H),
+        format!("{}", 1.0 * si::SIE),
+        format!("{}", 1.0 * si::T),
+        format!("{}", 1.0 * si::WB),
+    ];
+    assert_eq!(
+        tokens,
+        ["1 A", "1 C", "1 V", "1 Ω", "1 F", "1 H", "1 S", "1 T", "1 Wb"]
+    );
+}
+
+#[test]
+fn prefixed_conversions() {
+    assert_eq!((1500.0 * si::M).as_km(), 1.5);
+    assert_eq!((1.5 * si::M).as_mm(), 1500.0);
+    assert_eq!((0.25 * si::M).as_cm(), 25.0);
+    assert_eq!((2.0 * si::S).as_ms(), 2000.0);
+    assert_eq!((3e-9 * si::S).as_ns(), 3.0);
+    assert_eq!((1.5 * si::KG).as_g(), 1500.0);
+    assert_eq!((1.5 * si::KG).as_mg(), 1.5e6);
+    assert_eq!((0.5 * si::MOL).as_mmol(), 500.0);
+    assert_eq!((1500.0 * si::A).as_ka(), 1.5);
+    assert_eq!((0.25 * si::A).as_ma(), 250.0);
+    assert_eq!((0.25 * si::K).as_mk(), 250.0);
+    assert_eq!((2.0 * si::CD).as_mcd(), 2000.0);
+}
+
+fn assert_close<U>(a: si::SI<f64, U>, b: si::SI<f64, U>) {
+    assert!(
+        (a.value_unsafe - b.value_unsafe).abs() <= 1e-12 * b.value_unsafe.abs(),
+        "{} != {}",
+        a.value_unsafe,
+        b.value_unsafe
+    );
+}
+
+#[test]
+fn prefixed_constants() {
+    assert_eq!(1.0 * si::KM, 1000.0 * si::M);
+    assert_close(si::CM, 0.01 * si::M);
+    assert_close(si::MM, 1e-3 * si::M);
+    assert_close(si::UM, 1e-6 * si::M);
+    assert_close(si::NM, 1e-9 * si::M);
+    assert_close(si::MS, 1e-3 * si::S);
+    assert_close(si::US, 1e-6 * si::S);
+    assert_close(si::MA, 1e-3 * si::A);
+    assert_close(si::MK, 1e-3 * si::K);
+    assert_close(si::DAM, 10.0 * si::M);
+    assert_close(si::DM, 0.1 * si::M);
+    assert_close(si::YS, 1e-24 * si::S);
+
+    // Prefixes larger than kilo are spelled out, as are those that would clash with another
+    // constant
+    assert_close(si::MEGAM, 1e6 * si::M);
+    assert_close(si::YOTTAM, 1e24 * si::M);
+    assert_close(si::NANOS, 1e-9 * si::S);
+    assert_close(si::PICOM, 1e-12 * si::M);
+    assert_close(si::HECTOA, 100.0 * si::A);
+    assert_eq!(si::NS, si::N * si::S);
+    assert_eq!(si::HA, 1e4 * si::M2);
+
+    assert_eq!(si::f32consts::KM, 1000.0 * si::f32consts::M);
+    assert_eq!((2.5 * si::KM).as_km(), 2.5);
+    assert!(((3.0 * si::MS).as_ms() - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn derived_print_tokens() {
+    use dim::fmt::{Grouping, UnitFormat};
+
+    assert_eq!(format!("{}", 2.0 * si::KG * si::M / si::S2), "2 N");
+    assert_eq!(format!("{}", 2.0 * si::N * si::M), "2 J");
+    assert_eq!(format!("{}", 2.0 * si::J / si::S), "2 W");
+    assert_eq!(format!("{}", 2.0 * si::A * si::S), "2 C");
+    assert_eq!(format!("{}", 2.0 * si::W / si::A), "2 V");
+    assert_eq!(format!("{}", 2.0 * si::C / si::V), "2 F");
+    assert_eq!(format!("{}", 2.0 * si::V / si::A), "2 Ω");
+    assert_eq!(format!("{}", 2.0 * si::A / si::V), "2 S");
+    assert_eq!(format!("{}", 2.0 * si::V * si::S), "2 Wb");
+    assert_eq!(format!("{}", 2.0 * si::WB / si::M2), "2 T");
+    assert_eq!(format!("{}", 2.0 * si::OHM * si::S), "2 H");
+    assert_eq!(format!("{:e}", 1500.0 * si::PA), "1.5e3 Pa");
+    assert_eq!(
+        format!(
+            "{}",
+            UnitFormat::new(2.0 * si::N).grouping(Grouping::Fraction)
+        ),
+        "2 N"
+    );
+
+    // Only exact matches print with a token; a torque is a joule, and nothing is factored out
+    assert_eq!(format!("{}", 2.0 * si::N * si::M), "2 J");
+    assert_eq!(format!("{}", 2.0 * si::N * si::M2), "2 m^3*kg*s^-2");
+    assert_eq!(format!("{}", 2.0 * si::W * si::S), "2 J");
+
+    // Units with no registered token fall back to the base units, including negative exponents
+    assert_eq!(format!("{}", 2.0 * si::N * si::S), "2 m*kg*s^-1");
+    assert_eq!(format!("{}", 2.0 / si::S), "2 s^-1");
+    assert_eq!(format!("{}", 2.0 * si::J / si::K), "2 m^2*kg*s^-2*K^-1");
+}
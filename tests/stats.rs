@@ -0,0 +1,114 @@
+extern crate dimensioned as dim;
+
+use dim::si;
+
+#[test]
+fn weighted_mean() {
+    use dim::stats::weighted_mean;
+
+    let uniform = [(1.0 * si::S, 2.0), (2.0 * si::S, 2.0), (6.0 * si::S, 2.0)];
+    assert_eq!(weighted_mean(&uniform), Some(3.0 * si::S));
+
+    let skewed = [(1.0 * si::S, 1.0), (2.0 * si::S, 0.0), (6.0 * si::S, 4.0)];
+    assert_eq!(weighted_mean(&skewed), Some(5.0 * si::S));
+
+    let weightless = [(1.0 * si::S, 0.0), (2.0 * si::S, 0.0)];
+    assert_eq!(weighted_mean(&weightless), None);
+
+    assert_eq!(weighted_mean::<si::Second<f64>>(&[]), None);
+}
+
+#[test]
+fn geometric_mean() {
+    use dim::stats::geometric_mean;
+    use dim::Abs;
+
+    let close = |a: si::Meter<f64>, b: si::Meter<f64>| (a - b).abs() < 1e-12 * b.abs();
+
+    let mean = geometric_mean(&[1.0 * si::M, 3.0 * si::M, 9.0 * si::M]).unwrap();
+    assert!(close(mean, 3.0 * si::M));
+
+    let mean = geometric_mean(&[5.0 * si::M]).unwrap();
+    assert!(close(mean, 5.0 * si::M));
+
+    // Would overflow if the product were taken directly
+    let mean = geometric_mean(&[1e200 * si::M, 1e200 * si::M, 1e-100 * si::M]).unwrap();
+    assert!(close(mean, 1e100 * si::M));
+
+    assert_eq!(
+        geometric_mean(&[2.0 * si::M, 0.0 * si::M]),
+        Some(0.0 * si::M)
+    );
+    assert!(geometric_mean(&[2.0 * si::M, -2.0 * si::M])
+        .unwrap()
+        .value_unsafe
+        .is_nan());
+    assert_eq!(geometric_mean::<si::Meter<f64>>(&[]), None);
+}
+
+#[test]
+fn median() {
+    use dim::stats::median;
+
+    let mut odd = [5.0 * si::K, 1.0 * si::K, 3.0 * si::K];
+    assert_eq!(median(&mut odd), Some(3.0 * si::K));
+    assert_eq!(odd, [1.0 * si::K, 3.0 * si::K, 5.0 * si::K]);
+
+    let mut even = [6.0 * si::K, -2.0 * si::K, 1.0 * si::K, 4.0 * si::K];
+    assert_eq!(median(&mut even), Some(2.5 * si::K));
+
+    assert_eq!(median(&mut [7.0 * si::K]), Some(7.0 * si::K));
+    assert_eq!(median::<si::Kelvin<f64>>(&mut []), None);
+}
+
+#[test]
+fn percentile() {
+    use dim::stats::percentile;
+
+    // 1 through 11 meters, shuffled
+    let mut lengths: Vec<_> = [7, 2, 11, 5, 1, 9, 3, 10, 6, 4, 8]
+        .iter()
+        .map(|&x| f64::from(x) * si::M)
+        .collect();
+    assert_eq!(percentile(&mut lengths, 90.0), Some(10.0 * si::M));
+    assert_eq!(percentile(&mut lengths, 0.0), Some(1.0 * si::M));
+    assert_eq!(percentile(&mut lengths, 100.0), Some(11.0 * si::M));
+    assert_eq!(percentile(&mut lengths, 25.0), Some(3.5 * si::M));
+
+    let mut pair = [10.0 * si::M, 0.0 * si::M];
+    assert_eq!(percentile(&mut pair, 90.0), Some(9.0 * si::M));
+
+    assert_eq!(percentile(&mut pair, -1.0), None);
+    assert_eq!(percentile(&mut pair, 100.5), None);
+    assert_eq!(percentile(&mut pair, ::std::f64::NAN), None);
+    assert_eq!(percentile::<si::Meter<f64>>(&mut [], 50.0), None);
+}
+
+#[test]
+fn min_max() {
+    use dim::stats::MinMax;
+
+    let mut extremes = MinMax::new();
+    assert_eq!(extremes.min(), None);
+    assert_eq!(extremes.max(), None);
+    assert_eq!(extremes.range(), None);
+
+    extremes.push(-2.0 * si::M);
+    assert_eq!(extremes.min(), Some(-2.0 * si::M));
+    assert_eq!(extremes.max(), Some(-2.0 * si::M));
+    assert_eq!(extremes.range(), Some(0.0 * si::M));
+
+    extremes.extend(vec![
+        3.0 * si::M,
+        ::std::f64::NAN * si::M,
+        -5.0 * si::M,
+        1.0 * si::M,
+    ]);
+    assert_eq!(extremes.min(), Some(-5.0 * si::M));
+    assert_eq!(extremes.max(), Some(3.0 * si::M));
+    assert_eq!(extremes.range(), Some(8.0 * si::M));
+
+    let mut only_nan = MinMax::<si::Meter<f64>>::default();
+    only_nan.push(::std::f64::NAN * si::M);
+    assert_eq!(only_nan.range(), None);
+}
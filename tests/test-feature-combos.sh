@@ -9,7 +9,7 @@ set -ev
 
 cargo test
 
-for f in test approx clapme quickcheck serde_test rand; do
+for f in test approx clapme quickcheck serde_test rand try_from; do
     echo cargo test --features $f
     cargo test --features $f
 done
@@ -51,6 +51,37 @@ mod quickchecking {
         }
     }
 
+    quickcheck!{
+        fn arbitrary_quantities(x: Meter<f64>, y: Meter<f64>, t: Second<f64>) -> bool {
+            x + y == y + x && x * t == t * x && x - x == 0.0 * M
+        }
+    }
+
+    use dim::test_util::{bounded, bounded_nonnegative};
+    use quickcheck::{Arbitrary, Gen};
+
+    #[derive(Clone, Debug)]
+    struct Particle {
+        mass: Kilogram<f64>,
+        velocity: MeterPerSecond<f64>,
+    }
+
+    impl Arbitrary for Particle {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            Particle {
+                mass: bounded_nonnegative(g, 10.0),
+                velocity: bounded(g, 3.0),
+            }
+        }
+    }
+
+    quickcheck!{
+        fn bounded_quantities(p: Particle) -> bool {
+            let energy = 0.5 * p.mass * p.velocity * p.velocity;
+            energy >= 0.0 * J && energy <= 45.0 * J
+        }
+    }
+
     use dim::Sqrt;
     quickcheck!{
         fn sqrt(x: f64) -> TestResult {
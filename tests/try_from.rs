@@ -0,0 +1,31 @@
+#![cfg(feature = "try_from")]
+
+extern crate dimensioned as dim;
+
+use dim::si;
+use std::convert::{TryFrom, TryInto};
+
+#[test]
+fn try_from_in_range() {
+    let x = si::Meter::new(1_000i64);
+
+    let y: si::Meter<i32> = x.try_into().unwrap();
+    assert_eq!(y, si::Meter::new(1_000i32));
+
+    assert_eq!(si::Meter::<i16>::try_from(x), Ok(si::Meter::new(1_000i16)));
+    assert_eq!(si::Meter::<u16>::try_from(x), Ok(si::Meter::new(1_000u16)));
+    assert_eq!(si::Meter::<i64>::try_from(y), Ok(x));
+}
+
+#[test]
+fn try_from_out_of_range() {
+    let big = si::Second::new(i64::from(::std::i32::MAX) + 1);
+    assert!(si::Second::<i32>::try_from(big).is_err());
+
+    let negative = si::Second::new(-1i32);
+    assert!(si::Second::<u32>::try_from(negative).is_err());
+    assert!(si::Second::<usize>::try_from(negative).is_err());
+
+    assert!(si::Second::<u8>::try_from(si::Second::new(256u16)).is_err());
+    assert!(si::Second::<i8>::try_from(si::Second::new(128u8)).is_err());
+}